@@ -0,0 +1,809 @@
+//! Implementation details of the macros in this crate. Nothing in here is
+//! part of the public API.
+
+use crate::mismatch_handler::mismatch_handler;
+use crate::{FieldList, MismatchInfo, RedactedDebug, VariantName};
+use std::any::{type_name, Any};
+use std::borrow::Cow;
+use std::error::Error;
+use std::fmt::{self, Debug, Write};
+use std::mem::{discriminant, Discriminant};
+use std::panic::{catch_unwind, AssertUnwindSafe, Location};
+use std::rc::Rc;
+use std::sync::Arc;
+#[cfg(feature = "derive")]
+pub use unsafe_get_derive::check_fields;
+#[cfg(feature = "proc-macro")]
+pub use unsafe_get_derive::let_assert;
+#[cfg(feature = "derive")]
+pub use unsafe_get_derive::project_variant;
+
+/// Formats the expected side of a diff in a panic message. With the `color`
+/// feature enabled, it's colored green, unless the `NO_COLOR` environment
+/// variable is set.
+fn expected(text: &str) -> String {
+    paint(text, "32")
+}
+
+/// Formats the actual side of a diff in a panic message. With the `color`
+/// feature enabled, it's colored red, unless the `NO_COLOR` environment
+/// variable is set.
+fn actual(text: &str) -> String {
+    paint(text, "31")
+}
+
+fn paint(text: &str, color: &str) -> String {
+    if colors_enabled() {
+        format!("\x1b[{}m{}\x1b[0m", color, text)
+    } else {
+        text.to_string()
+    }
+}
+
+/// See <https://no-color.org>: `NO_COLOR` disables colors when it's set to a
+/// non-empty value.
+fn colors_enabled() -> bool {
+    cfg!(feature = "color")
+        && !json_enabled()
+        && !matches!(std::env::var_os("NO_COLOR"), Some(value) if !value.is_empty())
+}
+
+/// Wraps the value that didn't match, so that it can be described in a
+/// panic message. Calling `(&&&Describe(&value)).describe()` uses the
+/// `RedactedDebug` implementation of the value if there is one, and its
+/// `Debug` implementation otherwise. If it implements neither, it falls
+/// back to the variant name if the value implements `VariantName`, and to
+/// the type name of the value if it doesn't. `variant` returns the variant
+/// name of the value, if it can be determined.
+pub struct Describe<'a, T>(pub &'a T);
+
+pub trait DescribeRedacted {
+    fn describe(&self) -> String;
+
+    fn variant(&self) -> Option<String>;
+}
+
+impl<T: RedactedDebug> DescribeRedacted for &&Describe<'_, T> {
+    fn describe(&self) -> String {
+        format_debug(&Redacted(self.0))
+    }
+
+    fn variant(&self) -> Option<String> {
+        Some(debug_variant_name(&Redacted(self.0)))
+    }
+}
+
+pub trait DescribeDebug {
+    fn describe(&self) -> String;
+
+    fn variant(&self) -> Option<String>;
+}
+
+impl<T: Debug> DescribeDebug for &&&Describe<'_, T> {
+    fn describe(&self) -> String {
+        format_debug(self.0)
+    }
+
+    fn variant(&self) -> Option<String> {
+        Some(debug_variant_name(self.0))
+    }
+}
+
+pub trait DescribeVariantName {
+    fn describe(&self) -> String;
+
+    fn variant(&self) -> Option<String>;
+}
+
+impl<T: VariantName> DescribeVariantName for &Describe<'_, T> {
+    fn describe(&self) -> String {
+        self.0.variant_name().to_string()
+    }
+
+    fn variant(&self) -> Option<String> {
+        Some(self.0.variant_name().to_string())
+    }
+}
+
+pub trait DescribeTypeName {
+    fn describe(&self) -> String;
+
+    fn variant(&self) -> Option<String>;
+}
+
+impl<T> DescribeTypeName for Describe<'_, T> {
+    fn describe(&self) -> String {
+        format!(
+            "a value of type {} (which doesn't implement Debug)",
+            type_name::<T>()
+        )
+    }
+
+    fn variant(&self) -> Option<String> {
+        None
+    }
+}
+
+/// Formats a field that differs in `assert_variant_eq!` like
+/// `pretty_assertions` formats the values of `assert_eq!`: the actual value
+/// on the left and the expected one on the right. Values that span multiple
+/// lines (e.g. with the `pretty-debug` feature) are shown as a line diff.
+pub fn field_difference(field: &str, left: &str, right: &str) -> String {
+    if !left.contains('\n') && !right.contains('\n') {
+        return format!(
+            "  field `{}`:\n     left: {}\n    right: {}",
+            field,
+            actual(left),
+            expected(right)
+        );
+    }
+    let mut output = format!("  field `{}`: Diff < left / right > :", field);
+    write_diff(&mut output, "    ", left, right);
+    output
+}
+
+/// Formats the mismatch between the actual value and the expected
+/// constructor or pattern as a diff, like `field_difference` does for
+/// multi-line fields. `report` appends it to the panic message when colors
+/// are enabled, so that the mismatch stands out in terminal output.
+fn mismatch_difference(value: &str, expected: &str) -> String {
+    let mut output = "Diff < actual / expected > :".to_string();
+    write_diff(&mut output, "", value, expected);
+    output
+}
+
+/// Writes the lines of a diff between `left` and `right` to `output`, with
+/// the lines that are only on the left colored like actual values and the
+/// lines that are only on the right colored like expected ones.
+fn write_diff(output: &mut String, indentation: &str, left: &str, right: &str) {
+    for line in diff_lines(left, right) {
+        let line = match line {
+            Line::Both(line) => format!(" {}", line),
+            Line::Left(line) => actual(&format!("<{}", line)),
+            Line::Right(line) => expected(&format!(">{}", line)),
+        };
+        write!(output, "\n{}{}", indentation, line).unwrap();
+    }
+}
+
+enum Line<'a> {
+    Both(&'a str),
+    Left(&'a str),
+    Right(&'a str),
+}
+
+/// The maximum size of the table used by `diff_lines`. Beyond that, the
+/// values are shown one after another instead of being diffed.
+const DIFF_LIMIT: usize = 1_000_000;
+
+/// Diffs two texts line by line, using the longest common subsequence of
+/// their lines.
+fn diff_lines<'a>(left: &'a str, right: &'a str) -> Vec<Line<'a>> {
+    let left: Vec<&str> = left.lines().collect();
+    let right: Vec<&str> = right.lines().collect();
+    if left.len().saturating_mul(right.len()) > DIFF_LIMIT {
+        return left
+            .into_iter()
+            .map(Line::Left)
+            .chain(right.into_iter().map(Line::Right))
+            .collect();
+    }
+    // common[i][j] is the length of the longest common subsequence of
+    // left[i..] and right[j..].
+    let mut common = vec![vec![0; right.len() + 1]; left.len() + 1];
+    for i in (0..left.len()).rev() {
+        for j in (0..right.len()).rev() {
+            common[i][j] = if left[i] == right[j] {
+                common[i + 1][j + 1] + 1
+            } else {
+                common[i + 1][j].max(common[i][j + 1])
+            };
+        }
+    }
+    let (mut i, mut j) = (0, 0);
+    let mut lines = Vec::new();
+    while i < left.len() || j < right.len() {
+        if i < left.len() && j < right.len() && left[i] == right[j] {
+            lines.push(Line::Both(left[i]));
+            i += 1;
+            j += 1;
+        } else if j == right.len() || (i < left.len() && common[i + 1][j] >= common[i][j + 1]) {
+            lines.push(Line::Left(left[i]));
+            i += 1;
+        } else {
+            lines.push(Line::Right(right[j]));
+            j += 1;
+        }
+    }
+    lines
+}
+
+/// Wraps the value that didn't match, so that the fields of its variant can
+/// be listed in a panic message. Calling `(&&Hint(&value)).hint(variant)`
+/// lists them if the value implements `FieldList`, and returns an empty
+/// string otherwise.
+pub struct Hint<'a, T>(pub &'a T);
+
+pub trait HintFields {
+    fn hint(&self, variant: Option<&str>) -> String;
+}
+
+impl<T: FieldList> HintFields for &Hint<'_, T> {
+    fn hint(&self, variant: Option<&str>) -> String {
+        let variant = match variant {
+            Some(variant) => format!("actual variant `{}`", variant),
+            None => "actual variant".to_string(),
+        };
+        match self.0.field_list() {
+            [] => format!(", {} has no fields", variant),
+            fields => format!(
+                ", {} has fields: {}",
+                variant,
+                fields
+                    .iter()
+                    .map(|(name, ty)| format!("{}: {}", name, ty))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        }
+    }
+}
+
+pub trait HintNone {
+    fn hint(&self, variant: Option<&str>) -> String;
+}
+
+impl<T> HintNone for Hint<'_, T> {
+    fn hint(&self, _variant: Option<&str>) -> String {
+        String::new()
+    }
+}
+
+/// A failed match in `get!` or one of the other panicking macros.
+/// `description` is the text of the panic message, the other fields are the
+/// structured data for the mismatch handler and the JSON mode.
+pub struct Failure {
+    pub expected: &'static str,
+    pub actual: Option<String>,
+    pub value: String,
+    pub description: String,
+}
+
+/// Panics with the panic message of `get!` for the given failure, after
+/// adding the matched expression, the context of a chained `get!` and the
+/// custom message to its description.
+#[track_caller]
+pub fn fail(
+    mut failure: Failure,
+    location: &str,
+    expression: &str,
+    context: &[&str],
+    message: Option<fmt::Arguments>,
+) -> ! {
+    write!(failure.description, " while matching `{}`", expression).unwrap();
+    if !context.is_empty() {
+        write!(failure.description, " (nested in {})", context.join(" -> ")).unwrap();
+    }
+    if let Some(message) = message {
+        write!(failure.description, ": {}", message).unwrap();
+    }
+    report(failure, location, expression, context)
+}
+
+/// Panics for a failure of one of the panicking macros, with its
+/// description prefixed by the location of the macro call. With colors
+/// enabled, a diff between the value and the expected constructor or
+/// pattern is appended to it. If a handler is
+/// installed with `set_mismatch_handler`, it formats the panic message.
+/// Otherwise, with the `UNSAFE_GET_PANIC_FORMAT` environment variable set
+/// to `json`, the panic message is a JSON object, which test reporters can
+/// parse.
+#[track_caller]
+pub fn report(failure: Failure, location: &str, expression: &str, context: &[&str]) -> ! {
+    let mut text = format!("{}: {}", location, failure.description);
+    if colors_enabled() && !failure.value.is_empty() {
+        write!(
+            text,
+            "\n{}",
+            mismatch_difference(&failure.value, failure.expected)
+        )
+        .unwrap();
+    }
+    if let Some(handler) = mismatch_handler() {
+        panic!(
+            "{}",
+            handler(MismatchInfo {
+                location,
+                expression,
+                expected: failure.expected,
+                actual: failure.actual.as_deref(),
+                value: &failure.value,
+                context,
+                message: &text,
+            })
+        )
+    } else if json_enabled() {
+        panic!(
+            "{{\"location\":{},\"expression\":{},\"expected\":{},\"actual\":{},\"value\":{},\"message\":{}}}",
+            json_string(location),
+            json_string(expression),
+            json_string(failure.expected),
+            failure
+                .actual
+                .as_deref()
+                .map_or("null".to_string(), json_string),
+            json_string(&failure.value),
+            json_string(&text)
+        )
+    } else {
+        panic!("{}", text)
+    }
+}
+
+/// Like `report`, for the methods generated by the derives. They're
+/// `#[track_caller]`, so the location is the one of the method call.
+#[track_caller]
+pub fn report_caller(failure: Failure, expression: &str) -> ! {
+    report(failure, &Location::caller().to_string(), expression, &[])
+}
+
+const PANIC_FORMAT_VARIABLE: &str = "UNSAFE_GET_PANIC_FORMAT";
+
+/// The problem `let_assert!` reports for a value that doesn't match a
+/// pattern: If the pattern starts with the constructor of the value, it's
+/// the fields that don't match.
+pub fn pattern_mismatch(pattern: &str, variant: Option<&str>) -> String {
+    let path = pattern
+        .split(|char: char| !(char.is_alphanumeric() || char == '_' || char == ':'))
+        .next()
+        .unwrap_or_default();
+    let constructor = path.rsplit("::").next().unwrap_or_default();
+    if !constructor.is_empty() && !pattern.contains('|') && variant == Some(constructor) {
+        format!("fields of {} don't match pattern", path)
+    } else {
+        "value doesn't match pattern".to_string()
+    }
+}
+
+fn json_enabled() -> bool {
+    matches!(std::env::var(PANIC_FORMAT_VARIABLE).as_deref(), Ok("json"))
+}
+
+/// Formats a string as a JSON string literal.
+fn json_string(string: &str) -> String {
+    let mut output = String::from('"');
+    for char in string.chars() {
+        match char {
+            '"' => output.push_str("\\\""),
+            '\\' => output.push_str("\\\\"),
+            '\n' => output.push_str("\\n"),
+            '\r' => output.push_str("\\r"),
+            '\t' => output.push_str("\\t"),
+            char if char.is_control() => write!(output, "\\u{:04x}", char as u32).unwrap(),
+            char => output.push(char),
+        }
+    }
+    output.push('"');
+    output
+}
+
+/// Formats a value with `RedactedDebug` in place of `Debug`.
+struct Redacted<'a, T>(&'a T);
+
+impl<T: RedactedDebug> Debug for Redacted<'_, T> {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt_redacted(formatter)
+    }
+}
+
+/// Formats the `Debug` output of a value for a panic message, pretty-printed
+/// with the `pretty-debug` feature and truncated to the limit returned by
+/// `debug_limit`.
+fn format_debug(value: &dyn Debug) -> String {
+    let mut output = Truncated {
+        text: String::new(),
+        limit: debug_limit(),
+    };
+    let result = if cfg!(feature = "pretty-debug") {
+        write!(output, "{:#?}", value)
+    } else {
+        write!(output, "{:?}", value)
+    };
+    if result.is_err() {
+        write!(
+            output.text,
+            "... (truncated after {} bytes, set {}=0 to see the full output)",
+            output.limit, DEBUG_LIMIT_VARIABLE
+        )
+        .unwrap();
+    }
+    output.text
+}
+
+const DEBUG_LIMIT_VARIABLE: &str = "UNSAFE_GET_DEBUG_LIMIT";
+
+const DEFAULT_DEBUG_LIMIT: usize = 10_000;
+
+/// The maximum length of `Debug` output in panic messages, in bytes. It can
+/// be overridden with the `UNSAFE_GET_DEBUG_LIMIT` environment variable,
+/// where `0` means no limit.
+fn debug_limit() -> usize {
+    match std::env::var(DEBUG_LIMIT_VARIABLE)
+        .ok()
+        .and_then(|limit| limit.parse().ok())
+    {
+        Some(0) => usize::MAX,
+        Some(limit) => limit,
+        None => DEFAULT_DEBUG_LIMIT,
+    }
+}
+
+/// A `fmt::Write` that stops with an error once `limit` bytes have been
+/// written, so that huge values don't get formatted completely.
+struct Truncated {
+    text: String,
+    limit: usize,
+}
+
+impl fmt::Write for Truncated {
+    fn write_str(&mut self, string: &str) -> fmt::Result {
+        let remaining = self.limit - self.text.len();
+        if string.len() <= remaining {
+            self.text.push_str(string);
+            Ok(())
+        } else {
+            let mut end = remaining;
+            while !string.is_char_boundary(end) {
+                end -= 1;
+            }
+            self.text.push_str(&string[..end]);
+            self.limit = self.text.len();
+            Err(fmt::Error)
+        }
+    }
+}
+
+/// Wraps the value that `get!` matches on. Calling
+/// `Scrutinee(value).peel()` dereferences `Box`es, and references to
+/// `Box`es, `Rc`s, `Arc`s and `Cow`s. Inherent methods take precedence over trait
+/// methods, so all other values are passed through unchanged by
+/// `PeelNone::peel`.
+pub struct Scrutinee<T>(pub T);
+
+impl<T> Scrutinee<Box<T>> {
+    pub fn peel(self) -> T {
+        *self.0
+    }
+}
+
+impl<'a, T> Scrutinee<&'a Box<T>> {
+    pub fn peel(self) -> &'a T {
+        self.0
+    }
+}
+
+impl<'a, T> Scrutinee<&'a mut Box<T>> {
+    pub fn peel(self) -> &'a mut T {
+        self.0
+    }
+}
+
+impl<'a, T> Scrutinee<&'a Rc<T>> {
+    pub fn peel(self) -> &'a T {
+        self.0
+    }
+}
+
+impl<'a, T> Scrutinee<&'a Arc<T>> {
+    pub fn peel(self) -> &'a T {
+        self.0
+    }
+}
+
+impl<'a, T: ToOwned> Scrutinee<&'a Cow<'_, T>> {
+    pub fn peel(self) -> &'a T {
+        self.0
+    }
+}
+
+pub trait PeelNone {
+    type Output;
+
+    fn peel(self) -> Self::Output;
+}
+
+impl<T> PeelNone for Scrutinee<T> {
+    type Output = T;
+
+    fn peel(self) -> T {
+        self.0
+    }
+}
+
+/// Wraps a field extracted by `get!` that is projected further into with a
+/// dot-path. `project` gets passed the projection three times, once for
+/// every way of holding the field: Owned fields are moved out of, fields
+/// behind references are borrowed. Only the matching closure is called.
+pub struct Project<T>(pub T);
+
+impl<'a, T> Project<&'a T> {
+    pub fn project<O, B, M>(
+        self,
+        _owned: impl FnOnce(T) -> O,
+        borrowed: impl FnOnce(&'a T) -> B,
+        _mutable: impl FnOnce(&'a mut T) -> M,
+    ) -> B {
+        borrowed(self.0)
+    }
+}
+
+impl<'a, T> Project<&'a mut T> {
+    pub fn project<O, B, M>(
+        self,
+        _owned: impl FnOnce(T) -> O,
+        _borrowed: impl FnOnce(&'a T) -> B,
+        mutable: impl FnOnce(&'a mut T) -> M,
+    ) -> M {
+        mutable(self.0)
+    }
+}
+
+pub trait ProjectOwned<T> {
+    fn project<'a, O, B, M>(
+        self,
+        owned: impl FnOnce(T) -> O,
+        _borrowed: impl FnOnce(&'a T) -> B,
+        _mutable: impl FnOnce(&'a mut T) -> M,
+    ) -> O
+    where
+        T: 'a;
+}
+
+impl<T> ProjectOwned<T> for Project<T> {
+    fn project<'a, O, B, M>(
+        self,
+        owned: impl FnOnce(T) -> O,
+        _borrowed: impl FnOnce(&'a T) -> B,
+        _mutable: impl FnOnce(&'a mut T) -> M,
+    ) -> O
+    where
+        T: 'a,
+    {
+        owned(self.0)
+    }
+}
+
+/// Wraps the value passed to `get!(unwrap value, ...)`. Every call to
+/// `unwrap_layer` unwraps one layer of `Option` or `Result` (or references
+/// to them), recording the failure if it finds a `None` or an `Err`. Values
+/// of other types are passed through unchanged by `UnwrapNone::unwrap_layer`.
+pub struct Unwrap<T>(pub Result<T, Failure>);
+
+impl<T> Unwrap<Option<T>> {
+    pub fn unwrap_layer(self) -> Unwrap<T> {
+        Unwrap(self.0.and_then(|option| option.ok_or_else(none)))
+    }
+}
+
+impl<'a, T> Unwrap<&'a Option<T>> {
+    pub fn unwrap_layer(self) -> Unwrap<&'a T> {
+        Unwrap(self.0.and_then(|option| option.as_ref().ok_or_else(none)))
+    }
+}
+
+impl<T, E: Debug> Unwrap<Result<T, E>> {
+    pub fn unwrap_layer(self) -> Unwrap<T> {
+        Unwrap(
+            self.0
+                .and_then(|result| result.map_err(|error| err(&error))),
+        )
+    }
+}
+
+impl<'a, T, E: Debug> Unwrap<&'a Result<T, E>> {
+    pub fn unwrap_layer(self) -> Unwrap<&'a T> {
+        Unwrap(self.0.and_then(|result| result.as_ref().map_err(err)))
+    }
+}
+
+fn none() -> Failure {
+    Failure {
+        expected: "Some(_)",
+        actual: Some("None".to_string()),
+        value: "None".to_string(),
+        description: "get!: expected Some(_), got None".to_string(),
+    }
+}
+
+fn err<E: Debug>(error: &E) -> Failure {
+    let value = format!("Err({:?})", error);
+    Failure {
+        expected: "Ok(_)",
+        actual: Some("Err".to_string()),
+        description: format!("get!: expected Ok(_), got {}", value),
+        value,
+    }
+}
+
+pub trait UnwrapNone {
+    fn unwrap_layer(self) -> Self;
+}
+
+impl<T> UnwrapNone for Unwrap<T> {
+    fn unwrap_layer(self) -> Self {
+        self
+    }
+}
+
+/// Wraps a value whose variant name is needed. Calling
+/// `(&NameOf(&value)).variant_name()` uses the `VariantName` implementation
+/// of the value if there is one, and falls back to extracting the name from
+/// the `Debug` output otherwise.
+pub struct NameOf<'a, T: ?Sized>(pub &'a T);
+
+pub trait NameOfVariantName {
+    fn variant_name(&self) -> String;
+}
+
+impl<T: VariantName + ?Sized> NameOfVariantName for NameOf<'_, T> {
+    fn variant_name(&self) -> String {
+        self.0.variant_name().to_string()
+    }
+}
+
+pub trait NameOfDebug {
+    fn variant_name(&self) -> String;
+}
+
+impl<T: Debug + ?Sized> NameOfDebug for &NameOf<'_, T> {
+    fn variant_name(&self) -> String {
+        debug_variant_name(self.0)
+    }
+}
+
+/// Extracts the name of a variant from the `Debug` output of a value. The
+/// formatting stops at the end of the name, so that it's cheap even for
+/// huge values.
+fn debug_variant_name<T: Debug + ?Sized>(value: &T) -> String {
+    let mut name = Identifier(String::new());
+    // The formatting fails once the identifier is complete.
+    let _ = write!(name, "{:?}", value);
+    name.0
+}
+
+/// A `fmt::Write` that collects the identifier at the start of the output
+/// and stops with an error at the first character that can't be part of it.
+struct Identifier(String);
+
+impl fmt::Write for Identifier {
+    fn write_str(&mut self, string: &str) -> fmt::Result {
+        match string.find(|char: char| !(char.is_alphanumeric() || char == '_')) {
+            Some(end) => {
+                self.0.push_str(&string[..end]);
+                Err(fmt::Error)
+            }
+            None => {
+                self.0.push_str(string);
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Wraps a reference to a value whose discriminant is needed. Calling
+/// `Discriminate(&value).discriminant()` returns the discriminant of the
+/// value, or of the referenced value if `value` is a reference. Other values
+/// are handled by `DiscriminateValue::discriminant`.
+pub struct Discriminate<T>(pub T);
+
+impl<'a, T> Discriminate<&'a &'a T> {
+    pub fn discriminant(self) -> Discriminant<T> {
+        discriminant(*self.0)
+    }
+}
+
+pub trait DiscriminateValue<T> {
+    fn discriminant(self) -> Discriminant<T>;
+}
+
+impl<T> DiscriminateValue<T> for Discriminate<&T> {
+    fn discriminant(self) -> Discriminant<T> {
+        discriminant(self.0)
+    }
+}
+
+/// Returns the given value unchanged. `debug_get!` wraps its checked debug
+/// expansion in it, so that it needs an `unsafe` block in debug builds, just
+/// like its `get_unchecked!` expansion does in release builds. Without it, code
+/// that forgets the `unsafe` block would only fail to compile in release
+/// builds, and code that has it would trigger `unused_unsafe` in debug builds.
+///
+/// # Safety
+///
+/// The function itself doesn't rely on anything, but it stands in for
+/// `get_unchecked!` in debug builds. So callers have to uphold the contract of
+/// `debug_get!`: the value has to be constructed with the given constructor.
+pub unsafe fn checked<T>(value: T) -> T {
+    value
+}
+
+/// Walks the source chain of an error, starting with the error itself, and
+/// returns the first error of type `T`. If there is none, it returns a
+/// description of the chain for the panic message of `expect_error_variant!`.
+pub fn find_source<'a, T: Error + 'static>(
+    error: &'a (dyn Error + 'static),
+) -> Result<&'a T, String> {
+    let mut chain = Vec::new();
+    let mut current = Some(error);
+    while let Some(error) = current {
+        if let Some(found) = error.downcast_ref::<T>() {
+            return Ok(found);
+        }
+        chain.push(error.to_string());
+        current = error.source();
+    }
+    Err(format!(
+        "no error of type {} in source chain: {}",
+        type_name::<T>(),
+        chain.join(" -> ")
+    ))
+}
+
+/// Downcasts a value to `T`. If it has a different type, it returns a
+/// description for the panic message of `downcast_get!`.
+pub fn downcast<T: Any>(value: &dyn Any) -> Result<&T, String> {
+    value.downcast_ref::<T>().ok_or_else(|| {
+        format!(
+            "expected a value of type {}, got a value of another type",
+            type_name::<T>()
+        )
+    })
+}
+
+/// Runs the given closure and returns its panic payload. If the closure
+/// returns normally, it returns a description for the panic message of
+/// `expect_panic_variant!`.
+pub fn catch_panic<R>(closure: impl FnOnce() -> R) -> Result<Box<dyn Any + Send>, String> {
+    match catch_unwind(AssertUnwindSafe(closure)) {
+        Ok(_) => Err("expected the closure to panic, but it returned".to_string()),
+        Err(payload) => Ok(payload),
+    }
+}
+
+/// Returns the message of a panic payload created by `panic!`, which is
+/// either a `String` or a `&'static str`.
+fn payload_message(payload: &(dyn Any + Send)) -> Option<String> {
+    match payload.downcast_ref::<String>() {
+        Some(message) => Some(message.clone()),
+        None => payload
+            .downcast_ref::<&'static str>()
+            .map(|message| message.to_string()),
+    }
+}
+
+/// Returns the message of a panic payload created by `panic!`. If the
+/// payload is neither a `String` nor a `&'static str`, it returns a
+/// description for the panic message of `expect_panic_variant!`.
+pub fn panic_message(payload: Box<dyn Any + Send>) -> Result<String, String> {
+    payload_message(&*payload)
+        .ok_or_else(|| "expected a panic message, got a payload of another type".to_string())
+}
+
+/// Downcasts a panic payload to `T`. If it has a different type, it returns
+/// a description for the panic message of `expect_panic_variant!`.
+pub fn downcast_payload<T: Any>(payload: Box<dyn Any + Send>) -> Result<T, String> {
+    match payload.downcast::<T>() {
+        Ok(payload) => Ok(*payload),
+        Err(payload) => Err(match payload_message(&*payload) {
+            Some(message) => format!(
+                "expected a payload of type {}, got the message {:?}",
+                type_name::<T>(),
+                message
+            ),
+            None => format!(
+                "expected a payload of type {}, got a payload of another type",
+                type_name::<T>()
+            ),
+        }),
+    }
+}
@@ -0,0 +1,105 @@
+/// `assert_fields_eq!` compares a value against a partial variant literal.
+/// Only the fields in the literal are compared, all other fields are
+/// ignored. Tuple variants can be compared with `_` for fields that should
+/// be ignored:
+///
+/// ```
+/// use unsafe_get::assert_fields_eq;
+///
+/// #[derive(Debug)]
+/// enum ExampleEnum {
+///   Foo { a: i32, b: String, c: bool },
+///   Bar(i32, String, bool),
+/// }
+///
+/// let value = ExampleEnum::Foo { a: 1, b: "foo".to_string(), c: true };
+/// assert_fields_eq!(value, ExampleEnum::Foo { b: "foo" });
+/// let value = ExampleEnum::Bar(1, "foo".to_string(), true);
+/// assert_fields_eq!(value, ExampleEnum::Bar(1, _, true));
+/// ```
+///
+/// On failure, the panic message lists every field that differs separately,
+/// with its actual value on the left and the expected value on the right,
+/// instead of showing the whole value:
+///
+/// ```should_panic
+/// use unsafe_get::assert_fields_eq;
+///
+/// #[derive(Debug)]
+/// enum ExampleEnum {
+///   Foo { a: i32, b: String, c: bool },
+///   Bar(i32, String, bool),
+/// }
+///
+/// let value = ExampleEnum::Bar(1, "foo".to_string(), true);
+/// // panics with:
+/// // assert_fields_eq!: fields of ExampleEnum::Bar differ
+/// //   field `0`:
+/// //      left: 1
+/// //     right: 2
+/// //   field `2`:
+/// //      left: true
+/// //     right: false
+/// assert_fields_eq!(value, ExampleEnum::Bar(2, _, false));
+/// ```
+///
+/// It's like [`assert_variant_eq!`](crate::assert_variant_eq), with an
+/// implicit `..` and support for tuple variant literals.
+///
+/// Tuple variant literals can have at most 16 fields. Longer ones are
+/// rejected with a compile error:
+///
+/// ```compile_fail
+/// use unsafe_get::assert_fields_eq;
+///
+/// #[derive(Debug)]
+/// enum ExampleEnum {
+///   Long(u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8),
+/// }
+///
+/// let value = ExampleEnum::Long(0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0);
+/// assert_fields_eq!(value, ExampleEnum::Long(_, _, _, _, _, _, _, _, _, _, _, _, _, _, _, _, 0));
+/// ```
+#[macro_export]
+macro_rules! assert_fields_eq {
+    (@fields $value:expr, $constructor:path, [$($fields:tt)*] $(..)?) => {
+        $crate::assert_variant_eq!(
+            @assert "assert_fields_eq!", $value, $constructor, stringify!($constructor), [..] $($fields)*
+        )
+    };
+    (@fields $value:expr, $constructor:path, [$($fields:tt)*] $field:tt : $expected:expr $(, $($rest:tt)*)?) => {
+        $crate::assert_fields_eq!(@fields $value, $constructor, [$($fields)* $field: $expected,] $($($rest)*)?)
+    };
+    (@tuple $value:expr, $constructor:path, $display:expr, [$($fields:tt)*] [$($indices:tt)*]) => {
+        $crate::assert_variant_eq!(@assert "assert_fields_eq!", $value, $constructor, $display, [..] $($fields)*)
+    };
+    (@tuple $value:expr, $constructor:path, $display:expr, [$($fields:tt)*] [] $($rest:tt)+) => {
+        ::core::compile_error!(concat!(
+            "assert_fields_eq!: tuple variant literals can have at most 16 fields, the one for ",
+            $display,
+            " has more"
+        ))
+    };
+    (@tuple $value:expr, $constructor:path, $display:expr, [$($fields:tt)*] [$index:tt $($indices:tt)*] _ $(, $($rest:tt)*)?) => {
+        $crate::assert_fields_eq!(@tuple $value, $constructor, $display, [$($fields)*] [$($indices)*] $($($rest)*)?)
+    };
+    (@tuple $value:expr, $constructor:path, $display:expr, [$($fields:tt)*] [$index:tt $($indices:tt)*] $expected:expr $(, $($rest:tt)*)?) => {
+        $crate::assert_fields_eq!(
+            @tuple $value, $constructor, $display, [$($fields)* $index: $expected,] [$($indices)*] $($($rest)*)?
+        )
+    };
+    ($value:expr, $first:ident $(:: $rest:ident)* ( $($fields:tt)* ) $(,)?) => {
+        $crate::assert_fields_eq!(
+            @tuple
+            $value,
+            $first $(:: $rest)*,
+            concat!(stringify!($first) $(, "::", stringify!($rest))*),
+            []
+            [0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15]
+            $($fields)*
+        )
+    };
+    ($value:expr, $constructor:path { $($fields:tt)* } $(,)?) => {
+        $crate::assert_fields_eq!(@fields $value, $constructor, [] $($fields)*)
+    };
+}
@@ -0,0 +1,52 @@
+/// `assert_same_variant!` asserts that two values are constructed with the
+/// same constructor, regardless of the values of their fields:
+///
+/// ```
+/// use unsafe_get::assert_same_variant;
+///
+/// #[derive(Debug)]
+/// enum ExampleEnum {
+///   Foo { field: i32 },
+///   Bar,
+/// }
+///
+/// assert_same_variant!(ExampleEnum::Foo { field: 1 }, ExampleEnum::Foo { field: 2 });
+/// ```
+///
+/// The values are only borrowed. On failure, the panic message names the
+/// variants of both values:
+///
+/// ```should_panic
+/// use unsafe_get::assert_same_variant;
+///
+/// #[derive(Debug)]
+/// enum ExampleEnum {
+///   Foo { field: i32 },
+///   Bar,
+/// }
+///
+/// // panics with "assert_same_variant!: expected the same variant, got Foo and Bar"
+/// assert_same_variant!(ExampleEnum::Foo { field: 1 }, ExampleEnum::Bar);
+/// ```
+#[macro_export]
+macro_rules! assert_same_variant {
+    ($left:expr, $right:expr $(,)?) => {{
+        use $crate::__private::DiscriminateValue as _;
+        match (&$left, &$right) {
+            (left, right) => {
+                if $crate::__private::Discriminate(left).discriminant()
+                    != $crate::__private::Discriminate(right).discriminant()
+                {
+                    $crate::get!(
+                        @report [stringify!($left), stringify!($right)] left,
+                        format!(
+                            "assert_same_variant!: expected the same variant, got {} and {}",
+                            $crate::variant_name!(left),
+                            $crate::variant_name!(right)
+                        )
+                    );
+                }
+            }
+        }
+    }};
+}
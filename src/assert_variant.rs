@@ -0,0 +1,66 @@
+/// `assert_variant!` asserts that a value is constructed with the given
+/// constructor, without extracting any fields:
+///
+/// ```
+/// use unsafe_get::assert_variant;
+///
+/// #[derive(Debug)]
+/// enum ExampleEnum {
+///   Foo { field: i32 },
+///   Bar { other_field: String },
+/// }
+///
+/// assert_variant!(ExampleEnum::Foo { field: 42 }, ExampleEnum::Foo);
+/// ```
+///
+/// If the value is constructed with a different constructor,
+/// `assert_variant!` panics with a message containing the value. Like
+/// with `assert!`, a format string and arguments can be passed in to add
+/// to that message:
+///
+/// ```should_panic
+/// use unsafe_get::assert_variant;
+///
+/// #[derive(Debug)]
+/// enum ExampleEnum {
+///   Foo { field: i32 },
+///   Bar { other_field: String },
+/// }
+///
+/// let value = ExampleEnum::Foo { field: 42 };
+/// assert_variant!(value, ExampleEnum::Bar, "in case {}", 1); // panics
+/// ```
+#[macro_export]
+macro_rules! assert_variant {
+    (@describe $constructor:path, $value:expr) => {
+        format!(
+            "assert_variant!: expected enum constructor: {}, got {}",
+            stringify!($constructor),
+            $crate::get!(@actual $value)
+        )
+    };
+    ($value:expr, $constructor:path $(,)?) => {{
+        match $crate::get!(@peel $value) {
+            $constructor { .. } => (),
+            #[allow(unreachable_patterns)]
+            value => $crate::get!(
+                @report [stringify!($value), stringify!($constructor)] value,
+                $crate::assert_variant!(@describe $constructor, value)
+            ),
+        }
+    }};
+    ($value:expr, $constructor:path, $($message:tt)+) => {{
+        match $crate::get!(@peel $value) {
+            $constructor { .. } => (),
+            #[allow(unreachable_patterns)]
+            value => $crate::get!(
+                @report [stringify!($value), stringify!($constructor)] value,
+                format!(
+                    "{}: {}",
+                    $crate::assert_variant!(@describe $constructor, value),
+                    format_args!($($message)+)
+                )
+            ),
+        }
+    }};
+}
@@ -0,0 +1,108 @@
+/// `assert_variant_eq!` asserts that a value is constructed with the given
+/// constructor and compares the listed fields to expected values. Fields
+/// that aren't listed are ignored when the pattern ends in `..`:
+///
+/// ```
+/// use unsafe_get::assert_variant_eq;
+///
+/// #[derive(Debug)]
+/// enum ExampleEnum {
+///   Foo { a: i32, b: String, c: bool },
+///   Bar,
+/// }
+///
+/// let value = ExampleEnum::Foo { a: 1, b: "foo".to_string(), c: true };
+/// assert_variant_eq!(value, ExampleEnum::Foo { b: "foo", .. });
+/// ```
+///
+/// Without `..`, all fields of the variant have to be listed.
+///
+/// On failure, the panic message lists every field that differs, with its
+/// actual value on the left and the expected value on the right, like
+/// `assert_eq!`:
+///
+/// ```should_panic
+/// use unsafe_get::assert_variant_eq;
+///
+/// #[derive(Debug)]
+/// enum ExampleEnum {
+///   Foo { a: i32, b: String, c: bool },
+///   Bar,
+/// }
+///
+/// let value = ExampleEnum::Foo { a: 1, b: "foo".to_string(), c: true };
+/// // panics with:
+/// // assert_variant_eq!: fields of ExampleEnum::Foo differ
+/// //   field `a`:
+/// //      left: 1
+/// //     right: 2
+/// //   field `b`:
+/// //      left: "foo"
+/// //     right: "bar"
+/// assert_variant_eq!(value, ExampleEnum::Foo { a: 2, b: "bar", .. });
+/// ```
+///
+/// Values that span multiple lines, e.g. with the `pretty-debug` feature,
+/// are shown as a line diff, with lines of the actual value marked with `<`
+/// and lines of the expected value marked with `>`.
+#[macro_export]
+macro_rules! assert_variant_eq {
+    (@assert $name:literal, $value:expr, $constructor:path, $display:expr, [$($rest:tt)*] $($field:tt : $expected:expr,)*) => {{
+        match $crate::get!(@peel $value) {
+            value => match &value {
+                $constructor { $($field: _,)* $($rest)* } => {
+                    let mut differences = ::std::vec::Vec::<::std::string::String>::new();
+                    $(
+                        #[allow(unreachable_patterns)]
+                        match &value {
+                            $constructor { $field: actual, .. } => {
+                                let expected = &$expected;
+                                if actual != expected {
+                                    differences.push($crate::__private::field_difference(
+                                        stringify!($field),
+                                        &$crate::get!(@actual actual),
+                                        &$crate::get!(@actual expected),
+                                    ));
+                                }
+                            }
+                            _ => ::core::unreachable!(),
+                        }
+                    )*
+                    if !differences.is_empty() {
+                        $crate::get!(
+                            @report [stringify!($value), $display] value,
+                            format!(
+                                "{}: fields of {} differ\n{}",
+                                $name,
+                                $display,
+                                differences.join("\n")
+                            )
+                        );
+                    }
+                }
+                #[allow(unreachable_patterns)]
+                _ => $crate::get!(
+                    @report [stringify!($value), $display] value,
+                    format!(
+                        "{}: expected enum constructor: {}, got {}",
+                        $name,
+                        $display,
+                        $crate::get!(@actual value)
+                    )
+                ),
+            },
+        }
+    }};
+    (@fields $value:expr, $constructor:path, [$($fields:tt)*] ..) => {
+        $crate::assert_variant_eq!(@assert "assert_variant_eq!", $value, $constructor, stringify!($constructor), [..] $($fields)*)
+    };
+    (@fields $value:expr, $constructor:path, [$($fields:tt)*]) => {
+        $crate::assert_variant_eq!(@assert "assert_variant_eq!", $value, $constructor, stringify!($constructor), [] $($fields)*)
+    };
+    (@fields $value:expr, $constructor:path, [$($fields:tt)*] $field:tt : $expected:expr $(, $($rest:tt)*)?) => {
+        $crate::assert_variant_eq!(@fields $value, $constructor, [$($fields)* $field: $expected,] $($($rest)*)?)
+    };
+    ($value:expr, $constructor:path { $($fields:tt)* } $(,)?) => {
+        $crate::assert_variant_eq!(@fields $value, $constructor, [] $($fields)*)
+    };
+}
@@ -0,0 +1,67 @@
+/// `assert_variant_ne!` asserts that a value is *not* constructed with the
+/// given constructor. It's the negative counterpart to
+/// [`assert_variant!`](crate::assert_variant):
+///
+/// ```
+/// use unsafe_get::assert_variant_ne;
+///
+/// #[derive(Debug)]
+/// enum Event {
+///   Sent { id: u32 },
+///   Error { message: String },
+/// }
+///
+/// assert_variant_ne!(Event::Sent { id: 1 }, Event::Error);
+/// ```
+///
+/// If the value is constructed with the given constructor,
+/// `assert_variant_ne!` panics with a message containing the value. Like
+/// with `assert!`, a format string and arguments can be passed in to add to
+/// that message:
+///
+/// ```should_panic
+/// use unsafe_get::assert_variant_ne;
+///
+/// #[derive(Debug)]
+/// enum Event {
+///   Sent { id: u32 },
+///   Error { message: String },
+/// }
+///
+/// let event = Event::Error { message: "boom".to_string() };
+/// assert_variant_ne!(event, Event::Error, "in case {}", 1); // panics
+/// ```
+#[macro_export]
+macro_rules! assert_variant_ne {
+    (@describe $constructor:path, $value:expr) => {
+        format!(
+            "assert_variant_ne!: expected enum constructor other than {}, got {}",
+            stringify!($constructor),
+            $crate::get!(@actual $value)
+        )
+    };
+    ($value:expr, $constructor:path $(,)?) => {{
+        match $crate::get!(@peel $value) {
+            value @ $constructor { .. } => $crate::get!(
+                @report [stringify!($value), concat!("not ", stringify!($constructor))] value,
+                $crate::assert_variant_ne!(@describe $constructor, value)
+            ),
+            #[allow(unreachable_patterns)]
+            _ => (),
+        }
+    }};
+    ($value:expr, $constructor:path, $($message:tt)+) => {{
+        match $crate::get!(@peel $value) {
+            value @ $constructor { .. } => $crate::get!(
+                @report [stringify!($value), concat!("not ", stringify!($constructor))] value,
+                format!(
+                    "{}: {}",
+                    $crate::assert_variant_ne!(@describe $constructor, value),
+                    format_args!($($message)+)
+                )
+            ),
+            #[allow(unreachable_patterns)]
+            _ => (),
+        }
+    }};
+}
@@ -0,0 +1,41 @@
+/// `checked_get!` works like [`get!`](crate::get), but checks at compile
+/// time that the given variant and fields exist on an enum that derives
+/// [`CheckedFields`](derive.CheckedFields.html). Errors point at the
+/// misspelled variant or field and suggest names that exist:
+///
+#[cfg_attr(not(feature = "test-only"), doc = "```")]
+#[cfg_attr(feature = "test-only", doc = "```ignore")]
+/// use unsafe_get::{checked_get, CheckedFields};
+///
+/// #[derive(Debug, CheckedFields)]
+/// enum Shape {
+///   Circle { radius: f64 },
+///   Rectangle(f64, f64),
+/// }
+///
+/// assert_eq!(checked_get!(Shape::Circle { radius: 1.0 }, Shape::Circle, radius), 1.0);
+/// assert_eq!(checked_get!(Shape::Rectangle(2.0, 3.0), Shape::Rectangle, 1), 3.0);
+/// ```
+///
+/// ```compile_fail
+/// use unsafe_get::{checked_get, CheckedFields};
+///
+/// #[derive(Debug, CheckedFields)]
+/// enum Shape {
+///   Circle { radius: f64 },
+/// }
+///
+/// checked_get!(Shape::Circle { radius: 1.0 }, Shape::Circle, raduis);
+/// ```
+///
+/// The constructor has to be given as `Enum::Variant`. All other arguments
+/// are passed on to `get!` unchanged, so everything `get!` supports works
+/// with `checked_get!` as well, but only single fields and tuples of fields
+/// are checked. `checked_get!` is only available with the `derive` feature.
+#[macro_export]
+macro_rules! checked_get {
+    ($value:expr, $constructor:path $(, $($rest:tt)*)?) => {{
+        $crate::__private::check_fields!($constructor $(, $($rest)*)?);
+        $crate::get!($value, $constructor $(, $($rest)*)?)
+    }};
+}
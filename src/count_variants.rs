@@ -0,0 +1,52 @@
+/// `count_variants!` counts the elements of a collection (or anything else
+/// that implements `IntoIterator`) that are constructed with the given
+/// constructor:
+///
+/// ```
+/// use unsafe_get::count_variants;
+///
+/// #[derive(Debug)]
+/// enum Event {
+///   Retry { attempt: u32 },
+///   Done,
+/// }
+///
+/// let events = vec![Event::Retry { attempt: 1 }, Event::Retry { attempt: 2 }, Event::Done];
+/// assert_eq!(count_variants!(&events, Event::Retry), 2);
+/// assert_eq!(count_variants!(events.iter(), Event::Done), 1);
+/// ```
+///
+/// Instead of a constructor, you can also pass in a pattern, optionally with
+/// a guard:
+///
+/// ```
+/// use unsafe_get::count_variants;
+///
+/// #[derive(Debug)]
+/// enum Event {
+///   Retry { attempt: u32 },
+///   Done,
+/// }
+///
+/// let events = vec![Event::Retry { attempt: 1 }, Event::Retry { attempt: 2 }, Event::Done];
+/// assert_eq!(count_variants!(&events, Event::Retry { attempt: 2 }), 1);
+/// assert_eq!(count_variants!(&events, Event::Retry { attempt } if *attempt > 0), 2);
+/// ```
+#[macro_export]
+macro_rules! count_variants {
+    ($values:expr, $($constructor:ident)::+ $(,)?) => {
+        $crate::count_variants!($values, $($constructor)::+ { .. })
+    };
+    ($values:expr, $(|)? $($pattern:pat)|+ $(if $guard:expr)? $(,)?) => {
+        ::core::iter::Iterator::count(::core::iter::Iterator::filter(
+            ::core::iter::IntoIterator::into_iter($values),
+            |value| {
+                #[allow(unreachable_patterns)]
+                match value {
+                    $($pattern)|+ $(if $guard)? => true,
+                    _ => false,
+                }
+            },
+        ))
+    };
+}
@@ -0,0 +1,67 @@
+/// `debug_get!` is a middle ground between [`get!`](crate::get) and
+/// [`get_unchecked!`](crate::get_unchecked). With `debug_assertions` enabled
+/// it works like `get!` and panics with the full diagnostic on a mismatch.
+/// Without `debug_assertions` it works like `get_unchecked!`. Because of
+/// that, it can only be used inside an `unsafe` block:
+///
+/// ```
+/// use unsafe_get::debug_get;
+///
+/// #[derive(Debug)]
+/// enum ExampleEnum {
+///   Foo { field: i32 },
+///   Bar { other_field: String },
+/// }
+///
+/// let value = ExampleEnum::Foo { field: 42 };
+/// // SAFETY: `value` is constructed with `ExampleEnum::Foo`.
+/// let field = unsafe { debug_get!(value, ExampleEnum::Foo, field) };
+/// assert_eq!(field, 42);
+/// ```
+///
+/// It accepts the same arguments as `get_unchecked!`, a single field or a
+/// tuple of fields, in both debug and release builds. Chained constructors
+/// and the other forms of `get!` are rejected:
+///
+/// ```compile_fail
+/// use unsafe_get::debug_get;
+///
+/// enum Outer { Inner(Inner) }
+/// enum Inner { Leaf(i32) }
+///
+/// let value = Outer::Inner(Inner::Leaf(42));
+/// unsafe { debug_get!(value, Outer::Inner, 0, Inner::Leaf, 0) };
+/// ```
+///
+/// The `unsafe` block is required in debug builds as well, so code that
+/// compiles in one build profile compiles in the other one, too:
+///
+/// ```compile_fail,E0133
+/// use unsafe_get::debug_get;
+///
+/// enum ExampleEnum { Foo { field: i32 } }
+///
+/// let value = ExampleEnum::Foo { field: 42 };
+/// debug_get!(value, ExampleEnum::Foo, field);
+/// ```
+///
+/// # Safety
+///
+/// The caller has to make sure that the value is constructed with the given
+/// constructor. If it's not, the behavior is undefined in release builds.
+#[macro_export]
+macro_rules! debug_get {
+    (@run $value:expr, $constructor:path, $field:tt) => {{
+        #[cfg(debug_assertions)]
+        let result = $crate::__private::checked($crate::get!(@parse [$value, stringify!($value)] [] $constructor, $field));
+        #[cfg(not(debug_assertions))]
+        let result = $crate::get_unchecked!($value, $constructor, $field);
+        result
+    }};
+    ($value:expr, $constructor:path, ($($field:ident),+ $(,)?) $(,)?) => {
+        $crate::debug_get!(@run $value, $constructor, ($($field),+))
+    };
+    ($value:expr, $constructor:path, $field:tt $(,)?) => {
+        $crate::debug_get!(@run $value, $constructor, $field)
+    };
+}
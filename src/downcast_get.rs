@@ -0,0 +1,62 @@
+/// `downcast_get!` downcasts a `&dyn Any` or a `Box<dyn Any>` to the enum
+/// type of the given constructor and then works like [`get!`](crate::get)
+/// on a reference to the result:
+///
+/// ```
+/// use std::any::Any;
+/// use unsafe_get::downcast_get;
+///
+/// #[derive(Debug)]
+/// enum Message {
+///   Load { path: String },
+///   Unload,
+/// }
+///
+/// let message: Box<dyn Any> = Box::new(Message::Load { path: "plugin.so".to_string() });
+/// assert_eq!(downcast_get!(message, Message::Load, path), "plugin.so");
+/// ```
+///
+/// Like with `get!`, multiple fields can be returned at once by passing them
+/// in parentheses, and without a field only the constructor is checked.
+///
+/// `downcast_get!` panics with different messages depending on whether the
+/// value has a different type or is constructed with a different
+/// constructor.
+#[macro_export]
+macro_rules! downcast_get {
+    (@downcast $value:expr, $constructor:path, |$found:ident| $($arm:tt)*) => {
+        match $crate::__private::downcast(&*$value) {
+            ::core::result::Result::Ok(found) => match found {
+                $($arm)*
+                #[allow(unreachable_patterns)]
+                $found => $crate::get!(
+                    @report [stringify!($value), stringify!($constructor)] *$found,
+                    format!(
+                        "downcast_get!: expected enum constructor: {}, got {}",
+                        stringify!($constructor),
+                        $crate::get!(@actual *$found)
+                    )
+                ),
+            },
+            ::core::result::Result::Err(message) => $crate::get!(
+                @report [stringify!($value), stringify!($constructor), ::core::option::Option::None, ::std::string::String::new()]
+                format!("downcast_get!: {}", message)
+            ),
+        }
+    };
+    ($value:expr, $constructor:path $(,)?) => {
+        $crate::downcast_get!(@downcast $value, $constructor, |other|
+            $constructor { .. } => (),
+        )
+    };
+    ($value:expr, $constructor:path, ($($field:ident),+ $(,)?) $(,)?) => {
+        $crate::downcast_get!(@downcast $value, $constructor, |other|
+            $constructor { $($field,)+ .. } => ($($field),+),
+        )
+    };
+    ($value:expr, $constructor:path, $field:tt $(,)?) => {
+        $crate::downcast_get!(@downcast $value, $constructor, |other|
+            $constructor { $field: field, .. } => field,
+        )
+    };
+}
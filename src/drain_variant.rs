@@ -0,0 +1,43 @@
+/// `drain_variant!` removes all elements constructed with the given
+/// constructor from a `Vec` and returns the given field of each removed
+/// element. The remaining elements are kept in the `Vec` in their original
+/// order:
+///
+/// ```
+/// use unsafe_get::drain_variant;
+///
+/// #[derive(Debug, PartialEq)]
+/// enum Event {
+///   Sent { id: u32 },
+///   Ack { seq: u32 },
+/// }
+///
+/// let mut events = vec![
+///   Event::Ack { seq: 1 },
+///   Event::Sent { id: 7 },
+///   Event::Ack { seq: 2 },
+/// ];
+/// assert_eq!(drain_variant!(&mut events, Event::Ack, seq), vec![1, 2]);
+/// assert_eq!(events, vec![Event::Sent { id: 7 }]);
+/// ```
+///
+/// Like with [`get!`](crate::get), multiple fields can be returned at once
+/// by passing them in parentheses. Without a field, the removed elements
+/// themselves are returned.
+#[macro_export]
+macro_rules! drain_variant {
+    ($values:expr, $constructor:path $(,)?) => {{
+        let values: &mut ::std::vec::Vec<_> = $values;
+        let (matching, rest) =
+            $crate::partition_variants!(::core::mem::take(values), $constructor);
+        *values = rest;
+        matching
+    }};
+    ($values:expr, $constructor:path, $($fields:tt)+) => {
+        $crate::extract_all!(
+            $crate::drain_variant!($values, $constructor),
+            $constructor,
+            $($fields)+
+        )
+    };
+}
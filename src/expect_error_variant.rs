@@ -0,0 +1,89 @@
+/// `expect_error_variant!` walks the source chain of an error (starting with
+/// the error itself) until it finds an error of the enum type of the given
+/// constructor. It then works like [`get!`](crate::get) on that error:
+///
+/// ```
+/// use std::{error::Error, fmt};
+/// use unsafe_get::expect_error_variant;
+///
+/// #[derive(Debug)]
+/// enum IoError {
+///   NotFound { path: String },
+///   PermissionDenied,
+/// }
+///
+/// impl fmt::Display for IoError {
+///   fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+///     write!(formatter, "io error")
+///   }
+/// }
+///
+/// impl Error for IoError {}
+///
+/// #[derive(Debug)]
+/// struct ConfigError(IoError);
+///
+/// impl fmt::Display for ConfigError {
+///   fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+///     write!(formatter, "couldn't load config")
+///   }
+/// }
+///
+/// impl Error for ConfigError {
+///   fn source(&self) -> Option<&(dyn Error + 'static)> {
+///     Some(&self.0)
+///   }
+/// }
+///
+/// let error = ConfigError(IoError::NotFound { path: "config.toml".to_string() });
+/// assert_eq!(expect_error_variant!(error, IoError::NotFound, path), "config.toml");
+/// ```
+///
+/// The error can be anything that coerces to `&(dyn Error + 'static)` when
+/// borrowed, e.g. a concrete error type or a `Box<dyn Error>` dereferenced
+/// with `*`. Like with `get!`, multiple fields can be returned at once by
+/// passing them in parentheses, and without a field only the constructor is
+/// checked.
+///
+/// If there's no error of the right type in the source chain,
+/// `expect_error_variant!` panics with a message listing the chain. If the
+/// error is constructed with a different constructor, it panics with a
+/// message containing the error.
+#[macro_export]
+macro_rules! expect_error_variant {
+    (@find $error:expr, $constructor:path, |$found:ident| $($arm:tt)*) => {
+        match $crate::__private::find_source(&$error) {
+            ::core::result::Result::Ok(found) => match found {
+                $($arm)*
+                #[allow(unreachable_patterns)]
+                $found => $crate::get!(
+                    @report [stringify!($error), stringify!($constructor)] *$found,
+                    format!(
+                        "expect_error_variant!: expected enum constructor: {}, got {}",
+                        stringify!($constructor),
+                        $crate::get!(@actual *$found)
+                    )
+                ),
+            },
+            ::core::result::Result::Err(message) => $crate::get!(
+                @report [stringify!($error), stringify!($constructor), ::core::option::Option::None, ::std::string::String::new()]
+                format!("expect_error_variant!: {}", message)
+            ),
+        }
+    };
+    ($error:expr, $constructor:path $(,)?) => {
+        $crate::expect_error_variant!(@find $error, $constructor, |other|
+            $constructor { .. } => (),
+        )
+    };
+    ($error:expr, $constructor:path, ($($field:ident),+ $(,)?) $(,)?) => {
+        $crate::expect_error_variant!(@find $error, $constructor, |other|
+            $constructor { $($field,)+ .. } => ($($field),+),
+        )
+    };
+    ($error:expr, $constructor:path, $field:tt $(,)?) => {
+        $crate::expect_error_variant!(@find $error, $constructor, |other|
+            $constructor { $field: field, .. } => field,
+        )
+    };
+}
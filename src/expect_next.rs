@@ -0,0 +1,82 @@
+/// `expect_next!` advances an iterator and binds the variables of a
+/// refutable pattern against the next item in the current scope, like
+/// [`let_assert!`](crate::let_assert). This makes step-by-step tests of
+/// event streams read linearly:
+///
+/// ```
+/// use unsafe_get::expect_next;
+///
+/// #[derive(Debug)]
+/// enum Event {
+///   Opened { path: String },
+///   Read { bytes: usize },
+///   Closed,
+/// }
+///
+/// let events = vec![
+///   Event::Opened { path: "foo.txt".to_string() },
+///   Event::Read { bytes: 42 },
+///   Event::Closed,
+/// ];
+/// let mut events = events.into_iter();
+/// expect_next!(events, Event::Opened { path });
+/// assert_eq!(path, "foo.txt");
+/// expect_next!(events, Event::Read { bytes });
+/// assert_eq!(bytes, 42);
+/// expect_next!(events, Event::Closed);
+/// ```
+///
+/// If the iterator is exhausted or the next item doesn't match the pattern,
+/// `expect_next!` panics with a message containing the pattern and the item.
+/// Like with `assert!`, a format string and arguments can be passed in to add
+/// to that message.
+#[macro_export]
+macro_rules! expect_next {
+    (@describe $pattern:pat, $item:expr) => {
+        match &$item {
+            ::core::option::Option::Some(item) => format!(
+                "expect_next!: expected pattern: {}, got {}",
+                stringify!($pattern),
+                $crate::get!(@actual item)
+            ),
+            ::core::option::Option::None => format!(
+                "expect_next!: expected pattern: {}, but the iterator is exhausted",
+                stringify!($pattern)
+            ),
+        }
+    };
+    (@fail $iterator:expr, $pattern:pat, $item:expr, $description:expr) => {
+        match &$item {
+            ::core::option::Option::Some(item) => {
+                $crate::get!(@report [stringify!($iterator), stringify!($pattern)] item, $description)
+            }
+            ::core::option::Option::None => $crate::get!(
+                @report [stringify!($iterator), stringify!($pattern), ::core::option::Option::None, ::std::string::String::new()]
+                $description
+            ),
+        }
+    };
+    ($iterator:expr, $pattern:pat $(,)?) => {
+        let item = ::core::iter::Iterator::next(&mut $iterator);
+        #[allow(irrefutable_let_patterns)]
+        let ::core::option::Option::Some($pattern) = item else {
+            $crate::expect_next!(@fail $iterator, $pattern, item, $crate::expect_next!(@describe $pattern, item))
+        };
+    };
+    ($iterator:expr, $pattern:pat, $($message:tt)+) => {
+        let item = ::core::iter::Iterator::next(&mut $iterator);
+        #[allow(irrefutable_let_patterns)]
+        let ::core::option::Option::Some($pattern) = item else {
+            $crate::expect_next!(
+                @fail $iterator,
+                $pattern,
+                item,
+                format!(
+                    "{}: {}",
+                    $crate::expect_next!(@describe $pattern, item),
+                    format_args!($($message)+)
+                )
+            )
+        };
+    };
+}
@@ -0,0 +1,86 @@
+/// `expect_panic_variant!` runs a closure and asserts that it panics. Given
+/// only the closure, it returns the panic message:
+///
+/// ```
+/// use unsafe_get::expect_panic_variant;
+///
+/// let message = expect_panic_variant!(|| panic!("invalid input: {}", 42));
+/// assert_eq!(message, "invalid input: 42");
+/// ```
+///
+/// Panic payloads created with `std::panic::panic_any` can be matched
+/// against a constructor, and fields are extracted like with
+/// [`get!`](crate::get):
+///
+/// ```
+/// use std::panic::panic_any;
+/// use unsafe_get::expect_panic_variant;
+///
+/// #[derive(Debug)]
+/// enum Failure {
+///   Invalid { input: i32 },
+///   Timeout,
+/// }
+///
+/// let input = expect_panic_variant!(
+///   || panic_any(Failure::Invalid { input: 42 }),
+///   Failure::Invalid,
+///   input
+/// );
+/// assert_eq!(input, 42);
+/// ```
+///
+/// Like with `get!`, multiple fields can be returned at once by passing them
+/// in parentheses, and without a field only the constructor is checked.
+///
+/// `expect_panic_variant!` panics if the closure doesn't panic, if the
+/// payload has a different type, or if it's constructed with a different
+/// constructor. Note that the panic of the closure is still reported by the
+/// panic hook.
+#[macro_export]
+macro_rules! expect_panic_variant {
+    (@payload $closure:expr, $constructor:path, |$found:ident| $($arm:tt)*) => {
+        match $crate::__private::catch_panic($closure).and_then($crate::__private::downcast_payload) {
+            ::core::result::Result::Ok(found) => match found {
+                $($arm)*
+                #[allow(unreachable_patterns)]
+                $found => $crate::get!(
+                    @report [stringify!($closure), stringify!($constructor)] $found,
+                    format!(
+                        "expect_panic_variant!: expected enum constructor: {}, got {}",
+                        stringify!($constructor),
+                        $crate::get!(@actual $found)
+                    )
+                ),
+            },
+            ::core::result::Result::Err(message) => $crate::get!(
+                @report [stringify!($closure), stringify!($constructor), ::core::option::Option::None, ::std::string::String::new()]
+                format!("expect_panic_variant!: {}", message)
+            ),
+        }
+    };
+    ($closure:expr $(,)?) => {
+        match $crate::__private::catch_panic($closure).and_then($crate::__private::panic_message) {
+            ::core::result::Result::Ok(message) => message,
+            ::core::result::Result::Err(message) => $crate::get!(
+                @report [stringify!($closure), "a panic message", ::core::option::Option::None, ::std::string::String::new()]
+                format!("expect_panic_variant!: {}", message)
+            ),
+        }
+    };
+    ($closure:expr, $constructor:path $(,)?) => {
+        $crate::expect_panic_variant!(@payload $closure, $constructor, |other|
+            $constructor { .. } => (),
+        )
+    };
+    ($closure:expr, $constructor:path, ($($field:ident),+ $(,)?) $(,)?) => {
+        $crate::expect_panic_variant!(@payload $closure, $constructor, |other|
+            $constructor { $($field,)+ .. } => ($($field),+),
+        )
+    };
+    ($closure:expr, $constructor:path, $field:tt $(,)?) => {
+        $crate::expect_panic_variant!(@payload $closure, $constructor, |other|
+            $constructor { $field: field, .. } => field,
+        )
+    };
+}
@@ -0,0 +1,92 @@
+/// `expect_single!` asserts that exactly one element of a collection (or
+/// anything else that implements `IntoIterator`) is constructed with the
+/// given constructor, and returns the given field of that element:
+///
+#[cfg_attr(not(feature = "test-only"), doc = "```")]
+#[cfg_attr(feature = "test-only", doc = "```ignore")]
+/// use unsafe_get::expect_single;
+///
+/// #[derive(Debug)]
+/// enum Event {
+///   Sent { id: u32 },
+///   Error { message: String },
+/// }
+///
+/// let events = vec![
+///   Event::Sent { id: 1 },
+///   Event::Error { message: "boom".to_string() },
+///   Event::Sent { id: 2 },
+/// ];
+/// assert_eq!(expect_single!(&events, Event::Error, message), "boom");
+/// ```
+///
+/// Like with [`get!`](crate::get), multiple fields can be returned at once
+/// by passing them in parentheses. Without a field, the matching element
+/// itself is returned.
+///
+/// If no element or more than one element matches, `expect_single!` panics
+/// with a message containing all the matching elements:
+///
+#[cfg_attr(not(feature = "test-only"), doc = "```should_panic")]
+#[cfg_attr(feature = "test-only", doc = "```ignore")]
+/// use unsafe_get::expect_single;
+///
+/// #[derive(Debug)]
+/// enum Event {
+///   Sent { id: u32 },
+///   Error { message: String },
+/// }
+///
+/// let events = vec![Event::Sent { id: 1 }, Event::Sent { id: 2 }];
+/// expect_single!(&events, Event::Sent, id); // panics
+/// ```
+#[macro_export]
+macro_rules! expect_single {
+    (@describe $constructor:path, $matches:expr) => {{
+        let matches = &$matches;
+        if matches.is_empty() {
+            format!(
+                "expect_single!: expected exactly one element constructed with {}, got none",
+                stringify!($constructor)
+            )
+        } else {
+            format!(
+                "expect_single!: expected exactly one element constructed with {}, got {}: [{}]",
+                stringify!($constructor),
+                matches.len(),
+                ::core::iter::Iterator::collect::<::std::vec::Vec<_>>(
+                    ::core::iter::Iterator::map(matches.iter(), |value| $crate::get!(@actual value))
+                )
+                .join(", ")
+            )
+        }
+    }};
+    (@single $values:expr, $constructor:path) => {{
+        let mut matches = ::core::iter::Iterator::collect::<::std::vec::Vec<_>>(
+            ::core::iter::Iterator::filter(::core::iter::IntoIterator::into_iter($values), |value| {
+                ::core::matches!(value, $constructor { .. })
+            }),
+        );
+        if matches.len() != 1 {
+            $crate::get!(
+                @report [
+                    stringify!($values),
+                    stringify!($constructor),
+                    ::core::option::Option::None,
+                    $crate::get!(@actual matches)
+                ]
+                $crate::expect_single!(@describe $constructor, matches)
+            );
+        }
+        match matches.pop() {
+            ::core::option::Option::Some(value) => value,
+            ::core::option::Option::None => ::core::unreachable!(),
+        }
+    }};
+    ($values:expr, $constructor:path $(,)?) => {
+        $crate::expect_single!(@single $values, $constructor)
+    };
+    ($values:expr, $constructor:path, $($fields:tt)+) => {
+        $crate::get!($crate::expect_single!(@single $values, $constructor), $constructor, $($fields)+)
+    };
+}
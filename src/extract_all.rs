@@ -0,0 +1,36 @@
+/// `extract_all!` collects the given field from every element of a
+/// collection (or anything else that implements `IntoIterator`) that is
+/// constructed with the given constructor. Elements constructed with other
+/// constructors are skipped:
+///
+/// ```
+/// use unsafe_get::extract_all;
+///
+/// #[derive(Debug)]
+/// enum Event {
+///   Sent { payload: String },
+///   Ack { seq: u32 },
+/// }
+///
+/// let events = vec![
+///   Event::Sent { payload: "a".to_string() },
+///   Event::Ack { seq: 1 },
+///   Event::Sent { payload: "b".to_string() },
+/// ];
+/// assert_eq!(extract_all!(&events, Event::Sent, payload), vec!["a", "b"]);
+/// assert_eq!(extract_all!(events, Event::Ack, seq), vec![1]);
+/// ```
+///
+/// Like with [`get!`](crate::get), multiple fields can be extracted at once
+/// by passing them in parentheses, which results in a `Vec` of tuples.
+#[macro_export]
+macro_rules! extract_all {
+    ($values:expr, $constructor:path, $($fields:tt)+) => {
+        ::core::iter::Iterator::collect::<::std::vec::Vec<_>>(
+            ::core::iter::Iterator::filter_map(
+                ::core::iter::IntoIterator::into_iter($values),
+                |value| $crate::try_get!(value, $constructor, $($fields)+),
+            ),
+        )
+    };
+}
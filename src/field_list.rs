@@ -0,0 +1,33 @@
+/// Lists the fields of the constructor a value is constructed with. It can
+/// be implemented with `#[derive(FieldList)]` (with the `derive` feature
+/// enabled).
+///
+/// When [`get!`](crate::get) finds a different constructor than the
+/// expected one, and the value implements `FieldList`, the panic message
+/// lists the fields of the constructor that was found, e.g. "actual variant
+/// `Bar` has fields: bar: bool". That shows what could have been matched
+/// instead, without looking up the definition of the enum.
+pub trait FieldList {
+    /// Returns the names and types of the fields of the constructor `self`
+    /// is constructed with. Fields of tuple variants are named by their
+    /// position.
+    fn field_list(&self) -> &'static [(&'static str, &'static str)];
+}
+
+impl<T: FieldList + ?Sized> FieldList for &T {
+    fn field_list(&self) -> &'static [(&'static str, &'static str)] {
+        (**self).field_list()
+    }
+}
+
+impl<T: FieldList + ?Sized> FieldList for &mut T {
+    fn field_list(&self) -> &'static [(&'static str, &'static str)] {
+        (**self).field_list()
+    }
+}
+
+impl<T: FieldList + ?Sized> FieldList for Box<T> {
+    fn field_list(&self) -> &'static [(&'static str, &'static str)] {
+        (**self).field_list()
+    }
+}
@@ -0,0 +1,38 @@
+/// `get_cloned!` works like [`get!`](crate::get), but only borrows the
+/// given value and returns a clone of the field. This allows extracting
+/// fields from values that can't be moved, e.g. values behind `&self`:
+///
+#[cfg_attr(not(feature = "test-only"), doc = "```")]
+#[cfg_attr(feature = "test-only", doc = "```ignore")]
+/// use unsafe_get::get_cloned;
+///
+/// #[derive(Debug)]
+/// enum State {
+///   Loaded { items: Vec<String> },
+///   Loading,
+/// }
+///
+/// struct Fixture {
+///   state: State,
+/// }
+///
+/// impl Fixture {
+///   fn items(&self) -> Vec<String> {
+///     get_cloned!(self.state, State::Loaded, items)
+///   }
+/// }
+///
+/// let fixture = Fixture {
+///   state: State::Loaded { items: vec!["foo".to_string()] },
+/// };
+/// assert_eq!(fixture.items(), vec!["foo".to_string()]);
+/// ```
+///
+/// All forms of `get!` that return a single field are supported.
+#[macro_export]
+macro_rules! get_cloned {
+    ($value:expr, $($rest:tt)+) => {{
+        $crate::__test_only!("get_cloned!");
+        ::core::clone::Clone::clone($crate::get!(@parse [&$value, stringify!($value)] [] $($rest)+))
+    }};
+}
@@ -0,0 +1,32 @@
+/// `get_mut!` works like [`get!`](crate::get), but takes a mutable
+/// reference and returns a mutable reference to the field. This allows
+/// modifying a single field of a variant:
+///
+#[cfg_attr(not(feature = "test-only"), doc = "```")]
+#[cfg_attr(feature = "test-only", doc = "```ignore")]
+/// use unsafe_get::get_mut;
+///
+/// #[derive(Debug, PartialEq)]
+/// enum ExampleEnum {
+///   Foo { a: i32, b: i32 },
+///   Bar,
+/// }
+///
+/// let mut value = ExampleEnum::Foo { a: 1, b: 2 };
+/// *get_mut!(&mut value, ExampleEnum::Foo, a) = 3;
+/// assert_eq!(value, ExampleEnum::Foo { a: 3, b: 2 });
+/// ```
+///
+/// Like `get!`, `get_mut!` panics if the value is constructed with a
+/// different constructor.
+#[macro_export]
+macro_rules! get_mut {
+    (@named [$text:expr] $value:expr, $($rest:tt)+) => {{
+        $crate::__test_only!("get_mut!");
+        let value: &mut _ = $value;
+        $crate::get!(@parse [value, $text] [] $($rest)+)
+    }};
+    ($value:expr, $($rest:tt)+) => {
+        $crate::get_mut!(@named [stringify!($value)] $value, $($rest)+)
+    };
+}
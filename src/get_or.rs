@@ -0,0 +1,58 @@
+/// `get_or!` works like [`get!`](crate::get), but instead of panicking on a
+/// mismatching constructor it evaluates and returns a fallback expression:
+///
+/// ```
+/// use unsafe_get::get_or;
+///
+/// #[derive(Debug)]
+/// enum ExampleEnum {
+///   Foo { field: i32 },
+///   Bar { other_field: String },
+/// }
+///
+/// let value = ExampleEnum::Foo { field: 42 };
+/// assert_eq!(get_or!(value, ExampleEnum::Foo, field, 0), 42);
+///
+/// let value = ExampleEnum::Bar { other_field: "foo".to_string() };
+/// assert_eq!(get_or!(value, ExampleEnum::Foo, field, 0), 0);
+/// ```
+///
+/// Like `get!`, `get_or!` looks through `Box`es, and through references to
+/// `Box`es, `Rc`s, `Arc`s and `Cow`s.
+///
+/// The fallback expression is only evaluated if the constructor doesn't
+/// match. It may diverge, which allows skipping values, e.g. in loops:
+///
+/// ```
+/// use unsafe_get::get_or;
+///
+/// #[derive(Debug)]
+/// enum ExampleEnum {
+///   Foo { field: i32 },
+///   Bar,
+/// }
+///
+/// let values = vec![ExampleEnum::Foo { field: 1 }, ExampleEnum::Bar];
+/// let mut sum = 0;
+/// for value in values {
+///   sum += get_or!(value, ExampleEnum::Foo, field, continue);
+/// }
+/// assert_eq!(sum, 1);
+/// ```
+#[macro_export]
+macro_rules! get_or {
+    ($value:expr, $constructor:path, ($($field:ident),+ $(,)?), $default:expr $(,)?) => {{
+        match $crate::get!(@peel $value) {
+            $constructor { $($field,)+ .. } => ($($field),+),
+            #[allow(unreachable_patterns)]
+            _ => $default,
+        }
+    }};
+    ($value:expr, $constructor:path, $field:tt, $default:expr $(,)?) => {{
+        match $crate::get!(@peel $value) {
+            $constructor { $field: field, .. } => field,
+            #[allow(unreachable_patterns)]
+            _ => $default,
+        }
+    }};
+}
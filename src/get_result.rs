@@ -0,0 +1,56 @@
+/// `get_result!` works like [`get!`](crate::get), but returns a `Result`
+/// instead of panicking. On a mismatching constructor it returns an
+/// [`UnexpectedVariant`](crate::UnexpectedVariant) error, which composes
+/// with `?` in helpers that return `Result`s:
+///
+/// ```
+/// use unsafe_get::{get_result, UnexpectedVariant};
+///
+/// #[derive(Debug)]
+/// enum ExampleEnum {
+///   Foo { field: i32 },
+///   Bar,
+/// }
+///
+/// fn double_field(value: ExampleEnum) -> Result<i32, UnexpectedVariant> {
+///   Ok(get_result!(value, ExampleEnum::Foo, field)? * 2)
+/// }
+///
+/// assert_eq!(double_field(ExampleEnum::Foo { field: 21 }), Ok(42));
+/// assert_eq!(
+///   double_field(ExampleEnum::Bar),
+///   Err(UnexpectedVariant {
+///     expected: "ExampleEnum::Foo",
+///     actual: "Bar".to_string(),
+///     value: "Bar".to_string(),
+///   })
+/// );
+/// ```
+///
+/// Like `get!`, `get_result!` looks through `Box`es, and through references to
+/// `Box`es, `Rc`s, `Arc`s and `Cow`s.
+#[macro_export]
+macro_rules! get_result {
+    (@error $expected:expr, $value:expr) => {{
+        let value = $crate::get!(@actual $value);
+        $crate::UnexpectedVariant {
+            expected: $expected,
+            actual: $crate::get!(@variant $value).unwrap_or_else(|| value.clone()),
+            value,
+        }
+    }};
+    ($value:expr, $constructor:path, ($($field:ident),+ $(,)?) $(,)?) => {{
+        match $crate::get!(@peel $value) {
+            $constructor { $($field,)+ .. } => ::core::result::Result::Ok(($($field),+)),
+            #[allow(unreachable_patterns)]
+            value => ::core::result::Result::Err($crate::get_result!(@error stringify!($constructor), value)),
+        }
+    }};
+    ($value:expr, $constructor:path, $field:tt $(,)?) => {{
+        match $crate::get!(@peel $value) {
+            $constructor { $field: field, .. } => ::core::result::Result::Ok(field),
+            #[allow(unreachable_patterns)]
+            value => ::core::result::Result::Err($crate::get_result!(@error stringify!($constructor), value)),
+        }
+    }};
+}
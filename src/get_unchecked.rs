@@ -0,0 +1,45 @@
+/// `get_unchecked!` works like [`get!`](crate::get), but doesn't check that
+/// the value is constructed with the given constructor. Instead of panicking
+/// on a mismatch, it invokes undefined behavior, which allows the compiler to
+/// remove the branch entirely. Because of that, it can only be used inside
+/// an `unsafe` block:
+///
+/// ```
+/// use unsafe_get::get_unchecked;
+///
+/// #[derive(Debug)]
+/// enum ExampleEnum {
+///   Foo { field: i32 },
+///   Bar { other_field: String },
+/// }
+///
+/// let value = ExampleEnum::Foo { field: 42 };
+/// // SAFETY: `value` is constructed with `ExampleEnum::Foo`.
+/// let field = unsafe { get_unchecked!(value, ExampleEnum::Foo, field) };
+/// assert_eq!(field, 42);
+/// ```
+///
+/// Like `get!`, `get_unchecked!` looks through `Box`es, and through
+/// references to `Box`es, `Rc`s, `Arc`s and `Cow`s.
+///
+/// # Safety
+///
+/// The caller has to make sure that the value is constructed with the given
+/// constructor. If it's not, the behavior is undefined.
+#[macro_export]
+macro_rules! get_unchecked {
+    ($value:expr, $constructor:path, ($($field:ident),+ $(,)?) $(,)?) => {
+        match $crate::get!(@peel $value) {
+            $constructor { $($field,)+ .. } => ($($field),+),
+            #[allow(unreachable_patterns)]
+            _ => ::core::hint::unreachable_unchecked(),
+        }
+    };
+    ($value:expr, $constructor:path, $field:tt $(,)?) => {
+        match $crate::get!(@peel $value) {
+            $constructor { $field: field, .. } => field,
+            #[allow(unreachable_patterns)]
+            _ => ::core::hint::unreachable_unchecked(),
+        }
+    };
+}
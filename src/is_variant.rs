@@ -0,0 +1,36 @@
+/// `is_variant!` returns a predicate closure that checks whether a value is
+/// constructed with the given constructor. The closure takes the value by
+/// reference, so it can be passed to APIs expecting an
+/// `impl Fn(&T) -> bool`, e.g. `mockall::predicate::function` or
+/// `Iterator::filter`:
+///
+/// ```
+/// use unsafe_get::is_variant;
+///
+/// #[derive(Debug)]
+/// enum Event {
+///   Retry { attempt: u32 },
+///   Done,
+/// }
+///
+/// let events = vec![Event::Retry { attempt: 1 }, Event::Done];
+/// assert!(events.iter().any(is_variant!(Event::Done)));
+/// let is_retry = is_variant!(Event::Retry);
+/// assert!(is_retry(&events[0]));
+/// assert!(!is_retry(&events[1]));
+/// ```
+///
+/// To check the fields as well, use
+/// [`variant_where!`](crate::variant_where).
+#[macro_export]
+macro_rules! is_variant {
+    ($constructor:path $(,)?) => {
+        |value: &_| {
+            #[allow(unreachable_patterns)]
+            match value {
+                $constructor { .. } => true,
+                _ => false,
+            }
+        }
+    };
+}
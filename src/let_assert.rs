@@ -0,0 +1,269 @@
+/// `let_assert!` binds the variables of a refutable pattern in the current
+/// scope, like a `let` statement:
+///
+#[cfg_attr(not(feature = "test-only"), doc = "```")]
+#[cfg_attr(feature = "test-only", doc = "```ignore")]
+/// use unsafe_get::let_assert;
+///
+/// #[derive(Debug)]
+/// enum ExampleEnum {
+///   Foo { field: i32 },
+///   Bar,
+/// }
+///
+/// fn compute() -> ExampleEnum {
+///   ExampleEnum::Foo { field: 42 }
+/// }
+///
+/// let_assert!(ExampleEnum::Foo { field } = compute());
+/// assert_eq!(field, 42);
+/// ```
+///
+/// If the value doesn't match the pattern, `let_assert!` panics with a
+/// message showing the expression, its value and the pattern:
+///
+/// ```text
+/// let_assert!: value doesn't match pattern
+///   expression: compute()
+///        value: Bar
+///      pattern: ExampleEnum::Foo { field }
+/// ```
+///
+/// Sub-patterns like literals are checked as well, e.g. `Baz { a: 3, b }`
+/// only matches if `a` is `3`, and `@` binds a value that matches a
+/// sub-pattern, e.g. `Foo { field: n @ 1..=10 }`. If the value is constructed with the
+/// constructor the pattern starts with, the message says that its fields
+/// don't match instead, e.g. `fields of ExampleEnum::Baz don't match
+/// pattern`.
+///
+/// Patterns can be nested, e.g. `Ok(Some(ExampleEnum::Foo { field }))`, and
+/// the panic message always shows the whole pattern. To check several values
+/// at once, match a tuple of them, e.g. `(Ok(a), Some(b)) = (result,
+/// option)`. To check the shape of a slice, match a slice pattern, e.g.
+/// `[first, .., last] = &events[..]`.
+///
+/// Struct patterns can mix renamed and shorthand bindings, e.g.
+/// `Baz { a: x, b }`, and tuple patterns can skip fields with `..` in any
+/// position, e.g. `Triple(a, ..)` or `Triple(.., c)`. Bindings can be
+/// declared `mut`, e.g. `Foo { mut field }`, to modify them afterwards, and
+/// `ref` or `ref mut`, to borrow from a value that's still needed intact.
+/// Like in a `let` statement, a variable is matched against in place, so
+/// it's only moved out of by bindings that move. Matching against `&value`
+/// or `&mut value` binds references to the fields instead, and leaves
+/// `value` intact.
+///
+/// A guard can be passed in after the expression. It's checked after binding
+/// the variables, and if it doesn't hold, `let_assert!` panics with the
+/// value it got and the guard:
+///
+#[cfg_attr(not(feature = "test-only"), doc = "```should_panic")]
+#[cfg_attr(feature = "test-only", doc = "```ignore")]
+/// # use unsafe_get::let_assert;
+/// #
+/// # #[derive(Debug)]
+/// # enum ExampleEnum {
+/// #   Foo { field: i32 },
+/// #   Bar,
+/// # }
+/// #
+/// // panics with "let_assert!: guard failed [..] guard: field > 50"
+/// let_assert!(ExampleEnum::Foo { field } = ExampleEnum::Foo { field: 42 }, if field > 50);
+/// ```
+///
+/// Instead of panicking, `let_assert!` can run an `else` block that
+/// diverges, like `let .. else`, e.g. to skip the rest of a test:
+///
+#[cfg_attr(not(feature = "test-only"), doc = "```")]
+#[cfg_attr(feature = "test-only", doc = "```ignore")]
+/// # use unsafe_get::let_assert;
+/// #
+/// # #[derive(Debug)]
+/// # enum ExampleEnum {
+/// #   Foo { field: i32 },
+/// #   Bar,
+/// # }
+/// #
+/// for value in [ExampleEnum::Foo { field: 42 }, ExampleEnum::Bar] {
+///   let_assert!(ExampleEnum::Foo { field } = value, else { continue });
+///   assert_eq!(field, 42);
+/// }
+/// ```
+///
+/// Like with `assert!`, a format string and arguments can be passed in last
+/// to add to the panic message, e.g. to tell the cases of a data-driven test
+/// apart:
+///
+#[cfg_attr(not(feature = "test-only"), doc = "```should_panic")]
+#[cfg_attr(feature = "test-only", doc = "```ignore")]
+/// # use unsafe_get::let_assert;
+/// #
+/// # #[derive(Debug)]
+/// # enum ExampleEnum {
+/// #   Foo { field: i32 },
+/// #   Bar,
+/// # }
+/// #
+/// for (case, value) in [("foo", ExampleEnum::Foo { field: 42 }), ("bar", ExampleEnum::Bar)] {
+///   // panics with "let_assert!: value doesn't match pattern [..]
+///   // case bar: wrong value"
+///   let_assert!(ExampleEnum::Foo { field } = value, "case {}: wrong value", case);
+/// }
+/// ```
+///
+/// Several patterns can be bound in one `let_assert!`, separated by commas,
+/// e.g. `let_assert!(Foo { a } = first, Bar { b } = second)`. A guard, an
+/// `else` block or a message applies to the pattern it follows, so a
+/// message has to come last.
+///
+/// To use the bound variables in an expression, e.g. as a function argument,
+/// pass in a body after `=>`. `let_assert!` then returns its value:
+///
+#[cfg_attr(not(feature = "test-only"), doc = "```")]
+#[cfg_attr(feature = "test-only", doc = "```ignore")]
+/// # use unsafe_get::let_assert;
+/// #
+/// # #[derive(Debug)]
+/// # enum ExampleEnum {
+/// #   Foo { field: i32 },
+/// #   Bar,
+/// # }
+/// #
+/// let doubled = let_assert!(ExampleEnum::Foo { field } = ExampleEnum::Foo { field: 21 } => field * 2);
+/// assert_eq!(doubled, 42);
+/// ```
+///
+/// Like [`get!`](crate::get), `let_assert!` looks through boxes: if the
+/// expression evaluates to a `Box`, or to a reference to a `Box`, `Rc`,
+/// `Arc` or `Cow`, the pattern is matched against the value inside, e.g.
+/// `Expr::Binary { lhs, .. } = &node.child` for a `child: Box<Expr>`.
+/// Variables are matched against in place and aren't looked through, so to
+/// match the contents of a boxed variable, use `&boxed` or `*boxed`.
+///
+/// Patterns can have several alternatives, e.g. `Ok(n) | Err(n)`, and only
+/// panic if none of them matches. With the `proc-macro` feature enabled,
+/// `let_assert!` is implemented as a procedural macro, which reports syntax
+/// errors in the pattern at the offending token.
+#[macro_export]
+macro_rules! let_assert {
+    ($($input:tt)+) => {
+        $crate::__let_assert!($($input)+)
+    };
+}
+
+#[cfg(feature = "proc-macro")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __let_assert {
+    ($($input:tt)+) => {
+        $crate::__private::let_assert!($($input)+)
+    };
+}
+
+#[cfg(not(feature = "proc-macro"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __let_assert {
+    (@report [$($message:tt)*] [$($pattern:tt)+] $value:expr, $problem:expr, $variant:expr, $actual:expr $(, $details:expr)?) => {
+        $crate::get!(
+            @report [stringify!($value), stringify!($($pattern)+), $variant, ::std::clone::Clone::clone(&$actual)]
+            $crate::__let_assert!(
+                @message [$($message)*]
+                format!(
+                    "let_assert!: {}\n  expression: {}\n       value: {}\n     pattern: {}{}",
+                    $problem,
+                    stringify!($value),
+                    $actual,
+                    stringify!($($pattern)+),
+                    $crate::__let_assert!(@details $($details)?)
+                )
+            )
+        )
+    };
+    (@report_mismatch [$($message:tt)*] [$($pattern:tt)+] $value:expr, $variant:expr, $actual:expr) => {{
+        let variant: ::core::option::Option<::std::string::String> = $variant;
+        let problem = $crate::__private::pattern_mismatch(stringify!($($pattern)+), variant.as_deref());
+        $crate::__let_assert!(@report [$($message)*] [$($pattern)+] $value, problem, variant, $actual)
+    }};
+    (@details) => {
+        ""
+    };
+    (@details $details:expr) => {
+        $details
+    };
+    (@message [] $description:expr) => {
+        $description
+    };
+    (@message [$($message:tt)+] $description:expr) => {
+        format!("{}\n{}", $description, format_args!($($message)+))
+    };
+    (@bind [$scrutinee:ident] [$($message:tt)*] [$guard:expr] [$($pattern:tt)+] $value:expr) => {
+        // The value is moved into the bindings before the guard can be
+        // checked, so it's described up front.
+        let actual = $crate::get!(@actual $scrutinee);
+        let variant = $crate::get!(@variant $scrutinee);
+        #[allow(irrefutable_let_patterns, unused_parens)]
+        let ($($pattern)+) = $scrutinee else {
+            $crate::__let_assert!(@report_mismatch [$($message)*] [$($pattern)+] $value, variant, actual)
+        };
+        if !($guard) {
+            $crate::__let_assert!(
+                @report [$($message)*] [$($pattern)+] $value,
+                "guard failed",
+                variant,
+                actual,
+                concat!("\n       guard: ", stringify!($guard))
+            )
+        }
+    };
+    (@bind [$scrutinee:ident] [$($message:tt)*] [] [$($pattern:tt)+] $value:expr) => {
+        #[allow(irrefutable_let_patterns, unused_parens)]
+        let ($($pattern)+) = $scrutinee else {
+            $crate::__let_assert!(
+                @report_mismatch [$($message)*] [$($pattern)+] $value,
+                $crate::get!(@variant $scrutinee),
+                $crate::get!(@actual $scrutinee)
+            )
+        };
+    };
+    (@options [$scrutinee:ident] [$($pattern:tt)+] [$value:expr] else $fallback:block $(, $($rest:tt)*)?) => {
+        #[allow(irrefutable_let_patterns, unused_parens)]
+        let ($($pattern)+) = $scrutinee else $fallback;
+        $crate::__let_assert!($($($rest)*)?);
+    };
+    (@options [$scrutinee:ident] [$($pattern:tt)+] [$value:expr] if $guard:expr, $format:literal $($arguments:tt)*) => {
+        $crate::__let_assert!(@bind [$scrutinee] [$format $($arguments)*] [$guard] [$($pattern)+] $value);
+    };
+    (@options [$scrutinee:ident] [$($pattern:tt)+] [$value:expr] if $guard:expr $(, $($rest:tt)*)?) => {
+        $crate::__let_assert!(@bind [$scrutinee] [] [$guard] [$($pattern)+] $value);
+        $crate::__let_assert!($($($rest)*)?);
+    };
+    (@options [$scrutinee:ident] [$($pattern:tt)+] [$value:expr] $format:literal $($arguments:tt)*) => {
+        $crate::__let_assert!(@bind [$scrutinee] [$format $($arguments)*] [] [$($pattern)+] $value);
+    };
+    (@options [$scrutinee:ident] [$($pattern:tt)+] [$value:expr] $($rest:tt)*) => {
+        $crate::__let_assert!(@bind [$scrutinee] [] [] [$($pattern)+] $value);
+        $crate::__let_assert!($($rest)*);
+    };
+    () => {};
+    ($(|)? $($alternative:pat_param)|+ = $variable:ident $(, if $guard:expr)? => $body:expr $(, $($message:tt)+)?) => {{
+        $crate::__let_assert!($($alternative)|+ = $variable $(, if $guard)? $(, $($message)+)?);
+        $body
+    }};
+    ($(|)? $($alternative:pat_param)|+ = $value:expr $(, if $guard:expr)? => $body:expr $(, $($message:tt)+)?) => {{
+        $crate::__let_assert!($($alternative)|+ = $value $(, if $guard)? $(, $($message)+)?);
+        $body
+    }};
+    // In edition 2018, `pat` doesn't match patterns with a top-level `|`, so
+    // the alternatives are matched one by one. Variables are matched against
+    // in place, like in a `let` statement, so that `ref` bindings borrow from
+    // them.
+    ($(|)? $($alternative:pat_param)|+ = $variable:ident $(, $($rest:tt)*)?) => {
+        $crate::__test_only!("let_assert!");
+        $crate::__let_assert!(@options [$variable] [$($alternative)|+] [$variable] $($($rest)*)?);
+    };
+    ($(|)? $($alternative:pat_param)|+ = $value:expr $(, $($rest:tt)*)?) => {
+        $crate::__test_only!("let_assert!");
+        let value = $crate::get!(@peel $value);
+        $crate::__let_assert!(@options [value] [$($alternative)|+] [$value] $($($rest)*)?);
+    };
+}
@@ -65,7 +65,8 @@
 //! ```
 //! That only tests what you want to test, but is also a bit ugly. With `get!`
 //! you can write:
-//! ```
+#![cfg_attr(not(feature = "test-only"), doc = "```")]
+#![cfg_attr(feature = "test-only", doc = "```ignore")]
 //! # #[derive(Debug, PartialEq)]
 //! # enum ExampleEnum {
 //! #   Foo { a: i32, b: i32 },
@@ -82,10 +83,148 @@
 //!   assert_eq!(get!(computes_something(), ExampleEnum::Foo, b), 2);
 //! }
 //! ```
+//!
+//! # Derives
+//!
+//! With the `derive` feature enabled, this crate also provides derive macros
+//! that generate methods for accessing enum fields, e.g.
+//! [`VariantAccess`](derive.VariantAccess.html). In contrast to the macros,
+//! most of them generate checked accessors that can also be used outside of
+//! tests.
+//!
+//! # Restricting `get!` to tests
+//!
+//! With the `test-only` feature enabled, using `get!` (and the macros built
+//! on top of it), `unwrap_match!` or `let_assert!` outside of `cfg(test)` is
+//! a compile error. That's useful for enforcing that the panicking macros
+//! stay out of production code, when `unsafe_get` is a regular dependency.
+//! Note that doctests aren't compiled with `cfg(test)`, and there's no way
+//! to opt into it from within a doctest. That's why the examples in this
+//! documentation that use these macros are marked as `ignore` when the
+//! feature is enabled.
+//!
+//! # Panic messages
+//!
+//! The panic messages contain the `Debug` output of the values that didn't
+//! match. With the `pretty-debug` feature enabled, they're formatted with
+//! `{:#?}` instead of `{:?}`, which spreads large nested values over
+//! multiple lines.
+//!
+//! All macros in this crate that panic on a mismatch, and the methods
+//! generated by the derives that do, start their panic messages with the
+//! location of the call, e.g. `src/lib.rs:12:5: assert_variant!: ...`.
+//!
+//! `Debug` output longer than 10,000 bytes is truncated, so that huge
+//! values don't make the panic messages unreadable. The limit can be
+//! changed with the `UNSAFE_GET_DEBUG_LIMIT` environment variable, where `0`
+//! disables the truncation.
+//!
+//! With the `color` feature enabled, the panic messages end with a diff
+//! between the actual value and the expected constructor or pattern, like
+//! the ones `pretty_assertions` shows for `assert_eq!`, with the actual
+//! value colored red and the expected one green:
+//!
+//! ```text
+//! src/lib.rs:12:5: get!: expected enum constructor: Enum::Foo, got Bar while matching `value`
+//! Diff < actual / expected > :
+//! <Bar
+//! >Enum::Foo
+//! ```
+//!
+//! The differing fields in the panic messages of `assert_variant_eq!` and
+//! `assert_fields_eq!` are colored the same way. Setting the `NO_COLOR`
+//! environment variable to a non-empty value disables the colors and the
+//! diff again.
+//!
+//! Setting the `UNSAFE_GET_PANIC_FORMAT` environment variable to `json`
+//! makes the panicking macros and derived methods panic with a JSON object
+//! instead, so that test reporters can parse failures instead of matching
+//! on panic messages:
+//!
+//! ```text
+//! {"location":"src/lib.rs:12:5","expression":"value","expected":"Enum::Foo",
+//!  "actual":"Bar","value":"Bar { bar: true }","message":"..."}
+//! ```
+//!
+//! `expected` is the constructor or pattern as it was written, `actual` is
+//! the variant name of the value, or `null` if it can't be determined, and
+//! `message` is the panic message that would have been printed otherwise.
+//! `value` is empty for failures without a value, e.g. when `recv_variant!`
+//! times out. The methods generated by the derives use `self` as the
+//! `expression`.
+//!
+//! For full control over the panic messages of `get!` and the other
+//! panicking macros and derived methods, a handler that formats them can be
+//! installed with
+//! [`set_mismatch_handler`](crate::set_mismatch_handler).
+
+#[doc(hidden)]
+pub mod __private;
+mod assert_fields_eq;
+mod assert_same_variant;
+mod assert_variant;
+mod assert_variant_eq;
+mod assert_variant_ne;
+#[cfg(feature = "derive")]
+mod checked_get;
+mod count_variants;
+mod debug_get;
+mod downcast_get;
+mod drain_variant;
+mod expect_error_variant;
+mod expect_next;
+mod expect_panic_variant;
+mod expect_single;
+mod extract_all;
+mod field_list;
+mod get_cloned;
+mod get_mut;
+mod get_or;
+mod get_result;
+mod get_unchecked;
+mod is_variant;
+mod let_assert;
+mod map_variant;
+mod matches_get;
+mod mismatch_handler;
+mod ok;
+mod partition_variants;
+mod pattern_mismatch;
+mod poll;
+mod prism;
+mod project;
+mod recv_variant;
+mod redacted_debug;
+mod set_field;
+mod some;
+mod static_assert_variant;
+mod take_variant;
+mod test_only;
+mod try_get;
+mod try_let;
+mod unexpected_variant;
+mod unwrap_match;
+mod variant_name;
+mod variant_where;
+
+pub use field_list::FieldList;
+pub use mismatch_handler::{set_mismatch_handler, take_mismatch_handler, MismatchInfo};
+pub use pattern_mismatch::PatternMismatch;
+pub use prism::{Prism, Then};
+pub use redacted_debug::RedactedDebug;
+pub use unexpected_variant::UnexpectedVariant;
+#[cfg(feature = "derive")]
+pub use unsafe_get_derive::{
+    variant_test, CheckedFields, Constructors, ExpectVariant, FieldList, FieldsTuple,
+    PartialVariantEq, Prism, RedactedDebug, TryIntoVariant, Unwrap, VariantAccess, VariantKind,
+    VariantList, VariantName, VariantRefs, VariantStructs,
+};
+pub use variant_name::VariantName;
 
 /// The `get!` macro provides a non-total way to access enum fields:
 ///
-/// ```
+#[cfg_attr(not(feature = "test-only"), doc = "```")]
+#[cfg_attr(feature = "test-only", doc = "```ignore")]
 /// use unsafe_get::get;
 ///
 /// #[derive(Debug)]
@@ -98,11 +237,272 @@
 /// assert_eq!(get!(value, ExampleEnum::Foo, field), 42);
 /// ```
 ///
+/// Fields of tuple variants can be accessed by their position:
+///
+#[cfg_attr(not(feature = "test-only"), doc = "```")]
+#[cfg_attr(feature = "test-only", doc = "```ignore")]
+/// use unsafe_get::get;
+///
+/// #[derive(Debug)]
+/// enum ExampleEnum {
+///   Pair(i32, String),
+///   Bar,
+/// }
+///
+/// let value = ExampleEnum::Pair(42, "foo".to_string());
+/// assert_eq!(get!(value, ExampleEnum::Pair, 1), "foo");
+/// ```
+///
+/// Constructor paths may contain generic arguments, and a trailing comma is
+/// accepted after the last argument:
+///
+#[cfg_attr(not(feature = "test-only"), doc = "```")]
+#[cfg_attr(feature = "test-only", doc = "```ignore")]
+/// use unsafe_get::get;
+///
+/// let value: Result<u8, String> = Ok(42);
+/// assert_eq!(get!(value, Result::<u8, String>::Ok, 0,), 42);
+/// ```
+///
+/// `get!` consumes the value it is given. To keep the value around for
+/// further assertions, pass in a reference instead. `get!` then returns a
+/// reference to the field:
+///
+#[cfg_attr(not(feature = "test-only"), doc = "```")]
+#[cfg_attr(feature = "test-only", doc = "```ignore")]
+/// use unsafe_get::get;
+///
+/// #[derive(Debug)]
+/// enum ExampleEnum {
+///   Foo { field: String },
+///   Bar,
+/// }
+///
+/// let value = ExampleEnum::Foo { field: "foo".to_string() };
+/// let field: &String = get!(&value, ExampleEnum::Foo, field);
+/// assert_eq!(field, "foo");
+/// assert!(matches!(value, ExampleEnum::Foo { .. }));
+/// ```
+///
+/// `get!` looks through `Box`es, so that matching works on boxed values as
+/// well. `Rc`s, `Arc`s and `Cow`s can be looked through by passing in a
+/// reference to them:
+///
+#[cfg_attr(not(feature = "test-only"), doc = "```")]
+#[cfg_attr(feature = "test-only", doc = "```ignore")]
+/// use std::rc::Rc;
+/// use unsafe_get::get;
+///
+/// #[derive(Debug)]
+/// enum Expr {
+///   Binary { lhs: Box<Expr>, rhs: Box<Expr> },
+///   Literal { value: i32 },
+/// }
+///
+/// let expr = Box::new(Expr::Binary {
+///   lhs: Box::new(Expr::Literal { value: 1 }),
+///   rhs: Box::new(Expr::Literal { value: 2 }),
+/// });
+/// assert_eq!(get!(&expr, Expr::Binary, lhs, Expr::Literal, value), &1);
+/// assert_eq!(get!(expr, Expr::Binary, rhs, Expr::Literal, value), 2);
+///
+/// let shared = Rc::new(Expr::Literal { value: 3 });
+/// assert_eq!(get!(&shared, Expr::Literal, value), &3);
+/// ```
+///
+/// When no field is given, `get!` only checks the constructor and returns
+/// `()`. This is useful for unit variants:
+///
+#[cfg_attr(not(feature = "test-only"), doc = "```")]
+#[cfg_attr(feature = "test-only", doc = "```ignore")]
+/// use unsafe_get::get;
+///
+/// #[derive(Debug)]
+/// enum ExampleEnum {
+///   Done,
+///   Running { progress: u8 },
+/// }
+///
+/// get!(ExampleEnum::Done, ExampleEnum::Done);
+/// ```
+///
+/// Values wrapped in `Option`s or `Result`s (up to four layers deep) can be
+/// unwrapped before matching by prefixing them with `unwrap`. `get!` then
+/// panics if it encounters a `None` or an `Err`:
+///
+#[cfg_attr(not(feature = "test-only"), doc = "```")]
+#[cfg_attr(feature = "test-only", doc = "```ignore")]
+/// use unsafe_get::get;
+///
+/// #[derive(Debug)]
+/// enum ExampleEnum {
+///   Foo { field: i32 },
+///   Bar,
+/// }
+///
+/// fn computes_something() -> Result<Option<ExampleEnum>, String> {
+///   Ok(Some(ExampleEnum::Foo { field: 42 }))
+/// }
+///
+/// assert_eq!(get!(unwrap computes_something(), ExampleEnum::Foo, field), 42);
+/// ```
+///
+/// Prefixing the value with `const` makes `get!` usable in `const`
+/// contexts. Since values can't be formatted there, the panic message only
+/// contains the expected constructor. In constants, a mismatch fails
+/// compilation:
+///
+#[cfg_attr(not(feature = "test-only"), doc = "```")]
+#[cfg_attr(feature = "test-only", doc = "```ignore")]
+/// use unsafe_get::get;
+///
+/// enum Limit {
+///   Fixed { max: u32 },
+///   Unlimited,
+/// }
+///
+/// const LIMIT: Limit = Limit::Fixed { max: 10 };
+/// const MAX: u32 = get!(const LIMIT, Limit::Fixed, max);
+/// assert_eq!(MAX, 10);
+/// ```
+///
+/// ```compile_fail
+/// use unsafe_get::get;
+///
+/// enum Limit {
+///   Fixed { max: u32 },
+///   Unlimited,
+/// }
+///
+/// const MAX: u32 = get!(const Limit::Unlimited, Limit::Fixed, max);
+/// ```
+///
+/// The `const` form supports single fields, tuples of named fields and the
+/// unit form, but none of the other extensions described here.
+///
+/// Multiple named fields can be extracted at once by passing them as a
+/// tuple. `get!` then returns a tuple of their values:
+///
+#[cfg_attr(not(feature = "test-only"), doc = "```")]
+#[cfg_attr(feature = "test-only", doc = "```ignore")]
+/// use unsafe_get::get;
+///
+/// #[derive(Debug)]
+/// enum ExampleEnum {
+///   Foo { a: i32, b: String, c: bool },
+///   Bar,
+/// }
+///
+/// let value = ExampleEnum::Foo { a: 42, b: "foo".to_string(), c: true };
+/// assert_eq!(get!(value, ExampleEnum::Foo, (a, b)), (42, "foo".to_string()));
+/// ```
+///
+/// Instead of fields, a closure can be passed in. Its parameters name the
+/// fields that get bound, and `get!` returns the result of its body. This
+/// is handy when an assertion needs a value computed from several fields:
+///
+#[cfg_attr(not(feature = "test-only"), doc = "```")]
+#[cfg_attr(feature = "test-only", doc = "```ignore")]
+/// use unsafe_get::get;
+///
+/// #[derive(Debug)]
+/// enum ExampleEnum {
+///   Foo { a: i32, b: i32, c: bool },
+///   Bar,
+/// }
+///
+/// let value = ExampleEnum::Foo { a: 1, b: 2, c: true };
+/// assert_eq!(get!(value, ExampleEnum::Foo, |a, b| a + b), 3);
+/// ```
+///
+/// Other fields of the same variant can be pinned to literal values or
+/// ranges by passing them in braces after the extracted field. `get!`
+/// panics if any of them doesn't match, naming the field that didn't match
+/// and its actual value:
+///
+#[cfg_attr(not(feature = "test-only"), doc = "```")]
+#[cfg_attr(feature = "test-only", doc = "```ignore")]
+/// use unsafe_get::get;
+///
+/// #[derive(Debug)]
+/// enum ExampleEnum {
+///   Foo { a: i32, b: i32 },
+///   Bar,
+/// }
+///
+/// let value = ExampleEnum::Foo { a: 1, b: 2 };
+/// assert_eq!(get!(&value, ExampleEnum::Foo, b, { a: 1 }), &2);
+/// assert_eq!(get!(value, ExampleEnum::Foo, b, { a: 1..=9 }), 2);
+/// ```
+///
+/// Since plain structs can be matched with the same syntax, `get!` also
+/// works for them. Together with pinned fields, this allows checking parts
+/// of a struct while extracting others:
+///
+#[cfg_attr(not(feature = "test-only"), doc = "```")]
+#[cfg_attr(feature = "test-only", doc = "```ignore")]
+/// use unsafe_get::get;
+///
+/// #[derive(Debug)]
+/// struct Point {
+///   x: i32,
+///   y: i32,
+/// }
+///
+/// let point = Point { x: 1, y: 2 };
+/// assert_eq!(get!(point, Point, y, { x: 1 }), 2);
+/// ```
+///
+/// A guard can be passed in after named fields. `get!` then also panics
+/// if the constructor matches but the guard doesn't hold. The panic message
+/// contains the guard:
+///
+#[cfg_attr(not(feature = "test-only"), doc = "```should_panic")]
+#[cfg_attr(feature = "test-only", doc = "```ignore")]
+/// use unsafe_get::get;
+///
+/// #[derive(Debug)]
+/// enum ExampleEnum {
+///   Foo { field: i32 },
+///   Bar,
+/// }
+///
+/// let value = ExampleEnum::Foo { field: -1 };
+/// // panics with "get!: guard `field > 0` failed for Foo { field: -1 }
+/// // while matching `value`"
+/// let field = get!(value, ExampleEnum::Foo, field, if field > 0);
+/// ```
+///
+/// Values inside nested enums can be reached by chaining constructors and
+/// fields. Every constructor is matched against the field extracted in the
+/// previous step:
+///
+#[cfg_attr(not(feature = "test-only"), doc = "```")]
+#[cfg_attr(feature = "test-only", doc = "```ignore")]
+/// use unsafe_get::get;
+///
+/// #[derive(Debug)]
+/// enum Outer {
+///   Wrapped { inner: Inner },
+///   Empty,
+/// }
+///
+/// #[derive(Debug)]
+/// enum Inner {
+///   Leaf { x: i32 },
+///   Node,
+/// }
+///
+/// let value = Outer::Wrapped { inner: Inner::Leaf { x: 42 } };
+/// assert_eq!(get!(value, Outer::Wrapped, inner, Inner::Leaf, x), 42);
+/// ```
+///
 /// If the first argument to `get!` is constructed with a different
 /// constructor than the one passed in as the second argument, `get!`
 /// will panic.
 ///
-/// ```should_panic
+#[cfg_attr(not(feature = "test-only"), doc = "```should_panic")]
+#[cfg_attr(feature = "test-only", doc = "```ignore")]
 /// use unsafe_get::get;
 ///
 /// #[derive(Debug)]
@@ -114,17 +514,343 @@
 /// let value = ExampleEnum::Foo { field: 42 };
 /// let other_field = get!(value, ExampleEnum::Bar, other_field); // panics
 /// ```
+///
+/// The panic message contains the `Debug` output of the mismatching value.
+/// For types that don't implement `Debug`, it contains the name of their
+/// type instead. It also names the matched expression, as in "while
+/// matching \`value\`", which tells multiple extractions in one test apart.
+/// For values that implement [`FieldList`](crate::FieldList), it also lists
+/// the fields of the constructor that was found.
+/// Like the panic messages of all macros in this crate, it starts with the
+/// location of the `get!` call (`src/lib.rs:12:5: get!: ...`), so that the
+/// location isn't lost when a test harness or log aggregator strips the
+/// location that the panic is reported at.
+///
+/// Fields that hold structs can be projected into with a dot-path. When
+/// `get!` is given a reference, the projected field is borrowed as well:
+///
+#[cfg_attr(not(feature = "test-only"), doc = "```")]
+#[cfg_attr(feature = "test-only", doc = "```ignore")]
+/// use unsafe_get::get;
+///
+/// #[derive(Debug)]
+/// struct Config {
+///   timeout: u64,
+///   name: String,
+/// }
+///
+/// #[derive(Debug)]
+/// enum ExampleEnum {
+///   Foo { config: Config },
+///   Bar,
+/// }
+///
+/// let value = ExampleEnum::Foo {
+///   config: Config { timeout: 42, name: "foo".to_string() },
+/// };
+/// assert_eq!(get!(&value, ExampleEnum::Foo, config.name), "foo");
+/// assert_eq!(get!(value, ExampleEnum::Foo, config.timeout), 42);
+/// ```
+///
+/// When chaining, the panic message also says in which field of which
+/// constructor the mismatch was found.
+///
+/// A format string and arguments can be passed in after the last field.
+/// They get appended to the panic message, which helps to locate failures
+/// in loops or table-driven tests:
+///
+#[cfg_attr(not(feature = "test-only"), doc = "```should_panic")]
+#[cfg_attr(feature = "test-only", doc = "```ignore")]
+/// use unsafe_get::get;
+///
+/// #[derive(Debug)]
+/// enum ExampleEnum {
+///   Foo { field: i32 },
+///   Bar { other_field: String },
+/// }
+///
+/// for (id, value) in vec![ExampleEnum::Foo { field: 42 }].into_iter().enumerate() {
+///   // panics with "get!: expected enum constructor: ExampleEnum::Bar,
+///   // got Foo { field: 42 } while matching `value`: while checking request 0"
+///   get!(value, ExampleEnum::Bar, other_field, "while checking request {}", id);
+/// }
+/// ```
 #[macro_export]
 macro_rules! get {
-    ($value:expr, $constructor:path, $field:ident) => {{
-        if let $constructor { $field, .. } = $value {
-            $field
-        } else {
-            panic!(
-                "get!: expected enum constructor: {}, got {:?}",
-                stringify!($constructor),
-                $value
-            )
+    (@parse [$value:expr, $text:expr] [$($steps:tt)*] $constructor:path $(,)?) => {
+        $crate::get!(@run [$text] [] $value, $($steps)* ($constructor))
+    };
+    (@parse [$value:expr, $text:expr] [$($steps:tt)*] $constructor:path, |$($field:ident),+ $(,)?| $body:expr $(, $format:literal $(, $arguments:expr)*)? $(,)?) => {
+        $crate::get!(@run [$text] [$($format $(, $arguments)*)?] $value, $($steps)* ($constructor, |$($field),+| $body))
+    };
+    (@parse [$value:expr, $text:expr] [$($steps:tt)*] $constructor:path, $field:tt $(. $projection:tt)*, { $($sibling:tt : $expected:pat),* $(,)? } $(, $format:literal $(, $arguments:expr)*)? $(,)?) => {
+        $crate::get!(@run [$text] [$($format $(, $arguments)*)?] $value, $($steps)* ($constructor, $field $(. $projection)* { $($sibling: $expected),* }))
+    };
+    (@parse [$value:expr, $text:expr] [$($steps:tt)*] $constructor:path, $field:tt $(. $projection:tt)*, { $($sibling:tt : $expected:pat),* $(,)? }, $($rest:tt)+) => {
+        $crate::get!(@parse [$value, $text] [$($steps)* ($constructor, $field $(. $projection)* { $($sibling: $expected),* })] $($rest)+)
+    };
+    (@parse [$value:expr, $text:expr] [$($steps:tt)*] $constructor:path, $field:tt $(. $projection:tt)*, if $guard:expr $(, $format:literal $(, $arguments:expr)*)? $(,)?) => {
+        $crate::get!(@run [$text] [$($format $(, $arguments)*)?] $value, $($steps)* ($constructor, $field $(. $projection)* if $guard))
+    };
+    (@parse [$value:expr, $text:expr] [$($steps:tt)*] $constructor:path, $field:tt $(. $projection:tt)* $(, $format:literal $(, $arguments:expr)*)? $(,)?) => {
+        $crate::get!(@run [$text] [$($format $(, $arguments)*)?] $value, $($steps)* ($constructor, $field $(. $projection)*))
+    };
+    (@parse [$value:expr, $text:expr] [$($steps:tt)*] $constructor:path, $field:tt $(. $projection:tt)*, $($rest:tt)+) => {
+        $crate::get!(@parse [$value, $text] [$($steps)* ($constructor, $field $(. $projection)*)] $($rest)+)
+    };
+    (@run [$($context:expr),*] [$($message:tt)*] $value:expr, (@unwrap) $($rest:tt)+) => {{
+        use $crate::__private::UnwrapNone as _;
+        let unwrapped = $crate::__private::Unwrap(::core::result::Result::<_, $crate::__private::Failure>::Ok($value))
+            .unwrap_layer()
+            .unwrap_layer()
+            .unwrap_layer()
+            .unwrap_layer();
+        match unwrapped.0 {
+            ::core::result::Result::Ok(value) => $crate::get!(@run [$($context),*] [$($message)*] value, $($rest)+),
+            ::core::result::Result::Err(failure) => $crate::get!(@fail [$($context),*] [$($message)*] failure),
+        }
+    }};
+    (@run [$($context:expr),*] [$($message:tt)*] $value:expr, ($constructor:path)) => {{
+        match $crate::get!(@peel $value) {
+            $constructor { .. } => (),
+            #[allow(unreachable_patterns)]
+            value => $crate::get!(@fail [$($context),*] [$($message)*] $crate::get!(@mismatch $constructor, value)),
+        }
+    }};
+    (@run [$($context:expr),*] [$($message:tt)*] $value:expr, ($constructor:path, ($($field:ident),+ $(,)?) if $guard:expr)) => {{
+        match $crate::get!(@peel $value) {
+            $constructor { $($field,)+ .. } if $guard => ($($field),+),
+            value @ $constructor { .. } => {
+                $crate::get!(@fail [$($context),*] [$($message)*] $crate::get!(@guard_failed $constructor, $guard, value))
+            }
+            #[allow(unreachable_patterns)]
+            value => $crate::get!(@fail [$($context),*] [$($message)*] $crate::get!(@mismatch $constructor, value)),
+        }
+    }};
+    (@run [$($context:expr),*] [$($message:tt)*] $value:expr, ($constructor:path, $field:ident if $guard:expr)) => {{
+        match $crate::get!(@peel $value) {
+            $constructor { $field, .. } if $guard => $field,
+            value @ $constructor { .. } => {
+                $crate::get!(@fail [$($context),*] [$($message)*] $crate::get!(@guard_failed $constructor, $guard, value))
+            }
+            #[allow(unreachable_patterns)]
+            value => $crate::get!(@fail [$($context),*] [$($message)*] $crate::get!(@mismatch $constructor, value)),
+        }
+    }};
+    (@run [$($context:expr),*] [$($message:tt)*] $value:expr, ($constructor:path, $field:ident $(. $projection:tt)+ if $guard:expr)) => {{
+        $crate::get!(@project $crate::get!(@run [$($context),*] [$($message)*] $value, ($constructor, $field if $guard)), $(. $projection)+)
+    }};
+    (@run [$($context:expr),*] [$($message:tt)*] $value:expr, ($constructor:path, ($($field:ident),+ $(,)?) { $($sibling:tt : $expected:pat),* })) => {{
+        match $crate::get!(@peel $value) {
+            $constructor { $($field,)+ $($sibling: $expected,)* .. } => ($($field),+),
+            value @ $constructor { .. } => {
+                $crate::get!(@fail [$($context),*] [$($message)*] $crate::get!(@constraints_failed $constructor, value, $($sibling: $expected),*))
+            }
+            #[allow(unreachable_patterns)]
+            value => $crate::get!(@fail [$($context),*] [$($message)*] $crate::get!(@mismatch $constructor, value)),
+        }
+    }};
+    (@run [$($context:expr),*] [$($message:tt)*] $value:expr, ($constructor:path, $field:tt { $($sibling:tt : $expected:pat),* })) => {{
+        match $crate::get!(@peel $value) {
+            $constructor { $field: field, $($sibling: $expected,)* .. } => field,
+            value @ $constructor { .. } => {
+                $crate::get!(@fail [$($context),*] [$($message)*] $crate::get!(@constraints_failed $constructor, value, $($sibling: $expected),*))
+            }
+            #[allow(unreachable_patterns)]
+            value => $crate::get!(@fail [$($context),*] [$($message)*] $crate::get!(@mismatch $constructor, value)),
         }
     }};
+    (@run [$($context:expr),*] [$($message:tt)*] $value:expr, ($constructor:path, $field:tt $(. $projection:tt)+ { $($constraints:tt)* })) => {{
+        $crate::get!(@project $crate::get!(@run [$($context),*] [$($message)*] $value, ($constructor, $field { $($constraints)* })), $(. $projection)+)
+    }};
+    (@run [$($context:expr),*] [$($message:tt)*] $value:expr, ($constructor:path, ($($field:ident),+ $(,)?))) => {{
+        match $crate::get!(@peel $value) {
+            $constructor { $($field,)+ .. } => ($($field),+),
+            #[allow(unreachable_patterns)]
+            value => $crate::get!(@fail [$($context),*] [$($message)*] $crate::get!(@mismatch $constructor, value)),
+        }
+    }};
+    (@run [$($context:expr),*] [$($message:tt)*] $value:expr, ($constructor:path, |$($field:ident),+| $body:expr)) => {{
+        match $crate::get!(@peel $value) {
+            $constructor { $($field,)+ .. } => $body,
+            #[allow(unreachable_patterns)]
+            value => $crate::get!(@fail [$($context),*] [$($message)*] $crate::get!(@mismatch $constructor, value)),
+        }
+    }};
+    (@run [$($context:expr),*] [$($message:tt)*] $value:expr, ($constructor:path, $field:tt)) => {{
+        match $crate::get!(@peel $value) {
+            $constructor { $field: field, .. } => field,
+            #[allow(unreachable_patterns)]
+            value => $crate::get!(@fail [$($context),*] [$($message)*] $crate::get!(@mismatch $constructor, value)),
+        }
+    }};
+    (@run [$($context:expr),*] [$($message:tt)*] $value:expr, ($constructor:path, $field:tt $(. $projection:tt)+)) => {{
+        $crate::get!(@project $crate::get!(@run [$($context),*] [$($message)*] $value, ($constructor, $field)), $(. $projection)+)
+    }};
+    (@run [$($context:expr),*] [$($message:tt)*] $value:expr, ($constructor:path, $($field:tt)+) $($rest:tt)+) => {{
+        $crate::get!(
+            @run [$($context,)* concat!(stringify!($constructor), ".", stringify!($($field)+))] [$($message)*]
+            $crate::get!(@run [$($context),*] [$($message)*] $value, ($constructor, $($field)+)),
+            $($rest)+
+        )
+    }};
+    (@peel $value:expr) => {{
+        use $crate::__private::PeelNone as _;
+        $crate::__private::Scrutinee($value).peel()
+    }};
+    (@project $field:expr, $(. $projection:tt)+) => {{
+        use $crate::__private::ProjectOwned as _;
+        $crate::__private::Project($field).project(
+            |owned| owned $(. $projection)+,
+            |borrowed| &borrowed $(. $projection)+,
+            |borrowed| &mut borrowed $(. $projection)+,
+        )
+    }};
+    (@fail [$scrutinee:expr $(, $context:expr)*] [] $failure:expr) => {
+        $crate::__private::fail(
+            $failure,
+            $crate::get!(@location),
+            $scrutinee,
+            &[$($context),*],
+            ::core::option::Option::None,
+        )
+    };
+    (@fail [$scrutinee:expr $(, $context:expr)*] [$($message:tt)+] $failure:expr) => {
+        $crate::__private::fail(
+            $failure,
+            $crate::get!(@location),
+            $scrutinee,
+            &[$($context),*],
+            ::core::option::Option::Some(format_args!($($message)+)),
+        )
+    };
+    (@location) => {
+        ::core::concat!(::core::file!(), ":", ::core::line!(), ":", ::core::column!())
+    };
+    (@mismatch $constructor:path, $value:expr) => {{
+        use $crate::__private::{HintFields as _, HintNone as _};
+        let value = $crate::get!(@actual $value);
+        let actual = $crate::get!(@variant $value);
+        let description = format!(
+            "get!: expected enum constructor: {}, got {}{}",
+            stringify!($constructor),
+            value,
+            (&&$crate::__private::Hint(&$value)).hint(actual.as_deref())
+        );
+        $crate::__private::Failure {
+            expected: stringify!($constructor),
+            actual,
+            value,
+            description,
+        }
+    }};
+    (@guard_failed $constructor:path, $guard:expr, $value:expr) => {{
+        let value = $crate::get!(@actual $value);
+        $crate::__private::Failure {
+            expected: stringify!($constructor),
+            actual: $crate::get!(@variant $value),
+            description: format!(
+                "get!: guard `{}` failed for {}",
+                stringify!($guard),
+                value
+            ),
+            value,
+        }
+    }};
+    (@constraints_failed $constructor:path, $value:expr, $($sibling:tt : $expected:pat),*) => {{
+        let value = &$value;
+        let mut failures = ::std::vec::Vec::<::std::string::String>::new();
+        $(
+            if !::core::matches!(value, $constructor { $sibling: $expected, .. }) {
+                #[allow(unreachable_patterns)]
+                let actual = match value {
+                    $constructor { $sibling: sibling, .. } => $crate::get!(@actual sibling),
+                    _ => ::core::unreachable!(),
+                };
+                failures.push(format!(
+                    "expected field `{}` of {} to match `{}`, got {}",
+                    stringify!($sibling),
+                    stringify!($constructor),
+                    stringify!($expected),
+                    actual
+                ));
+            }
+        )*
+        $crate::__private::Failure {
+            expected: stringify!($constructor),
+            actual: $crate::get!(@variant value),
+            value: $crate::get!(@actual value),
+            description: format!("get!: {}", failures.join(", ")),
+        }
+    }};
+    (@report [$expression:expr, $expected:expr, $actual:expr, $described:expr] $description:expr) => {
+        $crate::__private::report(
+            $crate::__private::Failure {
+                expected: $expected,
+                actual: $actual,
+                value: $described,
+                description: $description,
+            },
+            $crate::get!(@location),
+            $expression,
+            &[],
+        )
+    };
+    (@report [$expression:expr, $expected:expr] $value:expr, $description:expr) => {
+        $crate::get!(
+            @report [$expression, $expected, $crate::get!(@variant $value), $crate::get!(@actual $value)]
+            $description
+        )
+    };
+    (@actual $value:expr) => {{
+        use $crate::__private::{
+            DescribeDebug as _, DescribeRedacted as _, DescribeTypeName as _, DescribeVariantName as _,
+        };
+        (&&&$crate::__private::Describe(&$value)).describe()
+    }};
+    (@variant $value:expr) => {{
+        use $crate::__private::{
+            DescribeDebug as _, DescribeRedacted as _, DescribeTypeName as _, DescribeVariantName as _,
+        };
+        (&&&$crate::__private::Describe(&$value)).variant()
+    }};
+    (@const $value:expr, $constructor:path $(,)?) => {
+        match $value {
+            $constructor { .. } => (),
+            #[allow(unreachable_patterns)]
+            _ => $crate::get!(@const_mismatch $constructor),
+        }
+    };
+    (@const $value:expr, $constructor:path, ($($field:ident),+ $(,)?) $(,)?) => {
+        match $value {
+            $constructor { $($field,)+ .. } => ($($field),+),
+            #[allow(unreachable_patterns)]
+            _ => $crate::get!(@const_mismatch $constructor),
+        }
+    };
+    (@const $value:expr, $constructor:path, $field:tt $(,)?) => {
+        match $value {
+            $constructor { $field: field, .. } => field,
+            #[allow(unreachable_patterns)]
+            _ => $crate::get!(@const_mismatch $constructor),
+        }
+    };
+    (const $($rest:tt)+) => {{
+        $crate::__test_only!("get!");
+        $crate::get!(@const $($rest)+)
+    }};
+    (@const_mismatch $constructor:path) => {
+        ::core::panic!(::core::concat!(
+            "get!: expected enum constructor: ",
+            ::core::stringify!($constructor)
+        ))
+    };
+    (unwrap $value:expr, $($rest:tt)+) => {{
+        $crate::__test_only!("get!");
+        $crate::get!(@parse [$value, stringify!($value)] [(@unwrap)] $($rest)+)
+    }};
+    ($value:expr, $($rest:tt)+) => {{
+        $crate::__test_only!("get!");
+        $crate::get!(@parse [$value, stringify!($value)] [] $($rest)+)
+    }};
 }
@@ -0,0 +1,34 @@
+/// `map_variant!` takes a value, modifies fields of the given variant and
+/// returns the modified value. The parameters of the closure name the
+/// fields, which are bound as mutable references. Fields that aren't named
+/// stay unchanged. This is handy for deriving slightly different fixtures
+/// from a base value:
+///
+#[cfg_attr(not(feature = "test-only"), doc = "```")]
+#[cfg_attr(feature = "test-only", doc = "```ignore")]
+/// use unsafe_get::map_variant;
+///
+/// #[derive(Debug, Clone, PartialEq)]
+/// enum ExampleEnum {
+///   Foo { a: i32, b: String, c: bool },
+///   Bar,
+/// }
+///
+/// let base = ExampleEnum::Foo { a: 1, b: "foo".to_string(), c: true };
+/// let derived = map_variant!(base.clone(), ExampleEnum::Foo, |a, c| {
+///   *a += 1;
+///   *c = false;
+/// });
+/// assert_eq!(derived, ExampleEnum::Foo { a: 2, b: "foo".to_string(), c: false });
+/// ```
+///
+/// Like [`get!`](crate::get), `map_variant!` panics if the value is
+/// constructed with a different constructor.
+#[macro_export]
+macro_rules! map_variant {
+    ($value:expr, $constructor:path, |$($field:ident),+ $(,)?| $body:expr $(,)?) => {{
+        let mut value = $value;
+        $crate::get!(&mut value, $constructor, |$($field),+| $body);
+        value
+    }};
+}
@@ -0,0 +1,27 @@
+/// `matches_get!` works like [`unwrap_match!`](crate::unwrap_match), but
+/// returns an `Option` instead of panicking. It returns `Some` with the
+/// result of the expression if the value matches the pattern, and `None`
+/// otherwise:
+///
+/// ```
+/// use unsafe_get::matches_get;
+///
+/// #[derive(Debug)]
+/// enum ExampleEnum {
+///   Foo { a: i32 },
+///   Bar,
+/// }
+///
+/// assert_eq!(matches_get!(ExampleEnum::Foo { a: 1 }, ExampleEnum::Foo { a } => a), Some(1));
+/// assert_eq!(matches_get!(ExampleEnum::Bar, ExampleEnum::Foo { a } => a), None);
+/// ```
+#[macro_export]
+macro_rules! matches_get {
+    ($value:expr, $pattern:pat $(if $guard:expr)? => $result:expr $(,)?) => {{
+        match $value {
+            $pattern $(if $guard)? => ::core::option::Option::Some($result),
+            #[allow(unreachable_patterns)]
+            _ => ::core::option::Option::None,
+        }
+    }};
+}
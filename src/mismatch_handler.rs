@@ -0,0 +1,75 @@
+use std::sync::RwLock;
+
+/// A failed match in [`get!`](crate::get) or one of the other panicking
+/// macros, as passed to the handler installed with
+/// [`set_mismatch_handler`].
+#[derive(Debug, Clone, Copy)]
+#[non_exhaustive]
+pub struct MismatchInfo<'a> {
+    /// The location of the macro call, as `file:line:column`.
+    pub location: &'a str,
+    /// The matched expression, as it was passed to the macro, or `self` for
+    /// the methods generated by the derives.
+    pub expression: &'a str,
+    /// The expected constructor or pattern, or e.g. `Some(_)` or `Ok(_)`
+    /// when unwrapping.
+    pub expected: &'a str,
+    /// The variant name of the value that didn't match, if it can be
+    /// determined.
+    pub actual: Option<&'a str>,
+    /// The `Debug` output of the value that didn't match. It's empty if
+    /// there is no such value, e.g. when `recv_variant!` times out.
+    pub value: &'a str,
+    /// The constructors and fields that a chained `get!` walked through
+    /// before the mismatch, e.g. `Outer::Wrapped.inner`.
+    pub context: &'a [&'a str],
+    /// The panic message that would have been used without a handler.
+    pub message: &'a str,
+}
+
+type Handler = fn(MismatchInfo) -> String;
+
+static HANDLER: RwLock<Option<Handler>> = RwLock::new(None);
+
+/// Installs a handler that formats the panic messages of
+/// [`get!`](crate::get) and the other panicking macros and derived methods,
+/// replacing any previously installed handler. It allows test harnesses to
+/// customize failures crate-wide, e.g. to add run ids or links:
+///
+#[cfg_attr(not(feature = "test-only"), doc = "```should_panic")]
+#[cfg_attr(feature = "test-only", doc = "```ignore")]
+/// use unsafe_get::{get, set_mismatch_handler, MismatchInfo};
+///
+/// #[derive(Debug)]
+/// enum ExampleEnum {
+///   Foo { field: i32 },
+///   Bar,
+/// }
+///
+/// set_mismatch_handler(|info: MismatchInfo| {
+///   format!("[run 42] {}", info.message)
+/// });
+/// // panics with "[run 42] <location>: get!: expected enum constructor:
+/// // ExampleEnum::Foo, got Bar while matching `ExampleEnum::Bar`"
+/// get!(ExampleEnum::Bar, ExampleEnum::Foo, field);
+/// ```
+///
+/// The handler is global, like the panic hook in `std::panic`, so it affects
+/// all threads.
+pub fn set_mismatch_handler(handler: Handler) {
+    *HANDLER.write().unwrap_or_else(|error| error.into_inner()) = Some(handler);
+}
+
+/// Removes the handler installed with [`set_mismatch_handler`] and returns
+/// it. Afterwards, the panicking macros use their default panic messages
+/// again.
+pub fn take_mismatch_handler() -> Option<Handler> {
+    HANDLER
+        .write()
+        .unwrap_or_else(|error| error.into_inner())
+        .take()
+}
+
+pub(crate) fn mismatch_handler() -> Option<Handler> {
+    *HANDLER.read().unwrap_or_else(|error| error.into_inner())
+}
@@ -0,0 +1,104 @@
+/// `ok!` unwraps the `Ok` side of a `Result`. If it's an `Err`, `ok!`
+/// panics with a message containing the unwrapped expression and the
+/// error:
+///
+/// ```
+/// use unsafe_get::ok;
+///
+/// assert_eq!(ok!("42".parse::<i32>()), 42);
+/// ```
+///
+/// ```should_panic
+/// use unsafe_get::ok;
+///
+/// // panics with "ok!: expected `\"foo\".parse::<i32>()` to be Ok(_),
+/// // got Err(ParseIntError { kind: InvalidDigit })"
+/// ok!("foo".parse::<i32>());
+/// ```
+///
+/// Like with `assert!`, a format string and arguments can be passed in to
+/// add to the panic message.
+#[macro_export]
+macro_rules! ok {
+    (@describe $result:expr, $error:expr) => {
+        format!(
+            "ok!: expected `{}` to be Ok(_), got Err({})",
+            stringify!($result),
+            $crate::get!(@actual $error)
+        )
+    };
+    (@fail $result:expr, $error:expr, $description:expr) => {
+        $crate::get!(
+            @report [stringify!($result), "Ok(_)", ::core::option::Option::Some("Err".to_string()), $crate::get!(@actual $error)]
+            $description
+        )
+    };
+    ($result:expr $(,)?) => {{
+        match $result {
+            ::core::result::Result::Ok(value) => value,
+            ::core::result::Result::Err(error) => $crate::ok!(@fail $result, error, $crate::ok!(@describe $result, error)),
+        }
+    }};
+    ($result:expr, $($message:tt)+) => {{
+        match $result {
+            ::core::result::Result::Ok(value) => value,
+            ::core::result::Result::Err(error) => $crate::ok!(
+                @fail $result,
+                error,
+                format!("{}: {}", $crate::ok!(@describe $result, error), format_args!($($message)+))
+            ),
+        }
+    }};
+}
+
+/// `err!` unwraps the `Err` side of a `Result`. If it's an `Ok`, `err!`
+/// panics with a message containing the unwrapped expression and the
+/// value:
+///
+/// ```
+/// use unsafe_get::err;
+///
+/// assert_eq!(err!("foo".parse::<i32>()).to_string(), "invalid digit found in string");
+/// ```
+///
+/// ```should_panic
+/// use unsafe_get::err;
+///
+/// // panics with "err!: expected `\"42\".parse::<i32>()` to be Err(_), got Ok(42)"
+/// err!("42".parse::<i32>());
+/// ```
+///
+/// Like with `assert!`, a format string and arguments can be passed in to
+/// add to the panic message.
+#[macro_export]
+macro_rules! err {
+    (@describe $result:expr, $value:expr) => {
+        format!(
+            "err!: expected `{}` to be Err(_), got Ok({})",
+            stringify!($result),
+            $crate::get!(@actual $value)
+        )
+    };
+    (@fail $result:expr, $value:expr, $description:expr) => {
+        $crate::get!(
+            @report [stringify!($result), "Err(_)", ::core::option::Option::Some("Ok".to_string()), $crate::get!(@actual $value)]
+            $description
+        )
+    };
+    ($result:expr $(,)?) => {{
+        match $result {
+            ::core::result::Result::Err(error) => error,
+            ::core::result::Result::Ok(value) => $crate::err!(@fail $result, value, $crate::err!(@describe $result, value)),
+        }
+    }};
+    ($result:expr, $($message:tt)+) => {{
+        match $result {
+            ::core::result::Result::Err(error) => error,
+            ::core::result::Result::Ok(value) => $crate::err!(
+                @fail $result,
+                value,
+                format!("{}: {}", $crate::err!(@describe $result, value), format_args!($($message)+))
+            ),
+        }
+    }};
+}
@@ -0,0 +1,44 @@
+/// `partition_variants!` splits a collection (or anything else that
+/// implements `IntoIterator`) by constructor. It returns a tuple with one
+/// `Vec` per given constructor, followed by a `Vec` with all the remaining
+/// elements:
+///
+/// ```
+/// use unsafe_get::partition_variants;
+///
+/// #[derive(Debug, PartialEq)]
+/// enum Event {
+///   Sent { id: u32 },
+///   Ack { seq: u32 },
+///   Done,
+/// }
+///
+/// let events = vec![
+///   Event::Sent { id: 1 },
+///   Event::Ack { seq: 1 },
+///   Event::Done,
+///   Event::Sent { id: 2 },
+/// ];
+/// let (sent, acks, rest) = partition_variants!(events, Event::Sent, Event::Ack);
+/// assert_eq!(sent, vec![Event::Sent { id: 1 }, Event::Sent { id: 2 }]);
+/// assert_eq!(acks, vec![Event::Ack { seq: 1 }]);
+/// assert_eq!(rest, vec![Event::Done]);
+/// ```
+///
+/// The order of the elements within each group is preserved.
+#[macro_export]
+macro_rules! partition_variants {
+    (@split [$($groups:expr),*] $rest:expr $(,)?) => {
+        ($($groups,)* $rest)
+    };
+    (@split [$($groups:expr),*] $rest:expr, $constructor:path $(, $constructors:path)* $(,)?) => {{
+        let (matching, rest): (::std::vec::Vec<_>, ::std::vec::Vec<_>) =
+            ::core::iter::Iterator::partition(::core::iter::IntoIterator::into_iter($rest), |value| {
+                ::core::matches!(value, $constructor { .. })
+            });
+        $crate::partition_variants!(@split [$($groups,)* matching] rest $(, $constructors)*)
+    }};
+    ($values:expr, $($constructors:path),+ $(,)?) => {
+        $crate::partition_variants!(@split [] $values, $($constructors),+)
+    };
+}
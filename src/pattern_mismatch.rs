@@ -0,0 +1,27 @@
+use std::error::Error;
+use std::fmt;
+
+/// The error returned by [`try_let!`](crate::try_let) when a value doesn't
+/// match the expected pattern.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PatternMismatch {
+    /// The expression that was matched, as it was passed to the macro.
+    pub expression: &'static str,
+    /// The expected pattern, as it was passed to the macro.
+    pub pattern: &'static str,
+    /// The `Debug` output of the value that didn't match, or the name of its
+    /// type if it doesn't implement `Debug`.
+    pub actual: String,
+}
+
+impl fmt::Display for PatternMismatch {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "expected `{}` to match pattern: {}, got {}",
+            self.expression, self.pattern, self.actual
+        )
+    }
+}
+
+impl Error for PatternMismatch {}
@@ -0,0 +1,103 @@
+/// `expect_ready!` unwraps a [`Poll`](std::task::Poll). If it's
+/// `Poll::Pending`, `expect_ready!` panics with a message containing the
+/// polled expression:
+///
+/// ```
+/// use std::task::Poll;
+/// use unsafe_get::expect_ready;
+///
+/// let poll = Poll::Ready(42);
+/// assert_eq!(expect_ready!(poll), 42);
+/// ```
+///
+/// For `Poll<Result<..>>` it can be combined with [`ok!`](crate::ok):
+///
+/// ```
+/// use std::task::Poll;
+/// use unsafe_get::{expect_ready, ok};
+///
+/// let poll: Poll<Result<i32, String>> = Poll::Ready(Ok(42));
+/// assert_eq!(ok!(expect_ready!(poll)), 42);
+/// ```
+///
+/// Like with `assert!`, a format string and arguments can be passed in to
+/// add to the panic message.
+#[macro_export]
+macro_rules! expect_ready {
+    (@describe $poll:expr) => {
+        format!(
+            "expect_ready!: expected `{}` to be Ready(_), got Pending",
+            stringify!($poll)
+        )
+    };
+    (@fail $poll:expr, $description:expr) => {
+        $crate::get!(
+            @report [stringify!($poll), "Ready(_)", ::core::option::Option::Some("Pending".to_string()), "Pending".to_string()]
+            $description
+        )
+    };
+    ($poll:expr $(,)?) => {{
+        match $poll {
+            ::core::task::Poll::Ready(value) => value,
+            ::core::task::Poll::Pending => $crate::expect_ready!(@fail $poll, $crate::expect_ready!(@describe $poll)),
+        }
+    }};
+    ($poll:expr, $($message:tt)+) => {{
+        match $poll {
+            ::core::task::Poll::Ready(value) => value,
+            ::core::task::Poll::Pending => $crate::expect_ready!(
+                @fail $poll,
+                format!("{}: {}", $crate::expect_ready!(@describe $poll), format_args!($($message)+))
+            ),
+        }
+    }};
+}
+
+/// `expect_pending!` asserts that a [`Poll`](std::task::Poll) is
+/// `Poll::Pending`. If it's `Poll::Ready`, `expect_pending!` panics with a
+/// message containing the polled expression and the ready value:
+///
+/// ```
+/// use std::task::Poll;
+/// use unsafe_get::expect_pending;
+///
+/// let poll: Poll<i32> = Poll::Pending;
+/// expect_pending!(poll);
+/// ```
+///
+/// Like with `assert!`, a format string and arguments can be passed in to
+/// add to the panic message.
+#[macro_export]
+macro_rules! expect_pending {
+    (@describe $poll:expr, $value:expr) => {
+        format!(
+            "expect_pending!: expected `{}` to be Pending, got Ready({})",
+            stringify!($poll),
+            $crate::get!(@actual $value)
+        )
+    };
+    (@fail $poll:expr, $value:expr, $description:expr) => {
+        $crate::get!(
+            @report [stringify!($poll), "Pending", ::core::option::Option::Some("Ready".to_string()), $crate::get!(@actual $value)]
+            $description
+        )
+    };
+    ($poll:expr $(,)?) => {{
+        match $poll {
+            ::core::task::Poll::Pending => (),
+            ::core::task::Poll::Ready(value) => {
+                $crate::expect_pending!(@fail $poll, value, $crate::expect_pending!(@describe $poll, value))
+            }
+        }
+    }};
+    ($poll:expr, $($message:tt)+) => {{
+        match $poll {
+            ::core::task::Poll::Pending => (),
+            ::core::task::Poll::Ready(value) => $crate::expect_pending!(
+                @fail $poll,
+                value,
+                format!("{}: {}", $crate::expect_pending!(@describe $poll, value), format_args!($($message)+))
+            ),
+        }
+    }};
+}
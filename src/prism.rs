@@ -0,0 +1,117 @@
+/// A prism focuses on the field of one enum variant. It's a composable
+/// version of `get!`: prisms for nested enums can be combined with
+/// [`then`](Prism::then) into a single prism that reaches through all
+/// layers.
+///
+/// Prisms are usually generated with `#[derive(Prism)]` (requires the
+/// `derive` feature), which generates an `Enum::foo_prism()` method for
+/// every variant `Foo` with exactly one field:
+///
+/// ```
+/// # #[cfg(feature = "derive")]
+/// # {
+/// use unsafe_get::Prism;
+///
+/// #[derive(Debug, PartialEq, Prism)]
+/// enum Response {
+///   Success(Body),
+///   Failure(String),
+/// }
+///
+/// #[derive(Debug, PartialEq, Prism)]
+/// enum Body {
+///   Text(String),
+///   Binary(Vec<u8>),
+/// }
+///
+/// let text = Response::success_prism().then(Body::text_prism());
+/// let response = text.review("hello".to_string());
+/// assert_eq!(response, Response::Success(Body::Text("hello".to_string())));
+/// assert_eq!(text.preview(&response), Some(&"hello".to_string()));
+/// assert_eq!(text.preview(&Response::Failure("oops".to_string())), None);
+/// let response = text.over(response, |text| text.to_uppercase());
+/// assert_eq!(text.preview(&response), Some(&"HELLO".to_string()));
+/// # }
+/// ```
+pub trait Prism {
+    /// The type the prism focuses into, usually an enum.
+    type Source;
+    /// The type the prism focuses on, usually the field of a variant.
+    type Focus;
+
+    /// Returns a reference to the focus, if `source` contains it.
+    fn preview<'a>(&self, source: &'a Self::Source) -> Option<&'a Self::Focus>;
+
+    /// Returns a mutable reference to the focus, if `source` contains it.
+    fn preview_mut<'a>(&self, source: &'a mut Self::Source) -> Option<&'a mut Self::Focus>;
+
+    /// Moves the focus out of `source`. Returns `source` unchanged if it
+    /// doesn't contain the focus.
+    fn extract(&self, source: Self::Source) -> Result<Self::Focus, Self::Source>;
+
+    /// Constructs a source from a focus.
+    fn review(&self, focus: Self::Focus) -> Self::Source;
+
+    /// Applies `f` to the focus, if `source` contains it. Returns `source`
+    /// unchanged otherwise.
+    fn over(&self, source: Self::Source, f: impl FnOnce(Self::Focus) -> Self::Focus) -> Self::Source
+    where
+        Self: Sized,
+    {
+        match self.extract(source) {
+            Ok(focus) => self.review(f(focus)),
+            Err(source) => source,
+        }
+    }
+
+    /// Composes two prisms into one that focuses on the focus of `next`
+    /// inside the focus of `self`. The focus of `self` can't contain
+    /// borrowed data.
+    fn then<P>(self, next: P) -> Then<Self, P>
+    where
+        Self: Sized,
+        Self::Focus: 'static,
+        P: Prism<Source = Self::Focus>,
+    {
+        Then {
+            first: self,
+            second: next,
+        }
+    }
+}
+
+/// Two prisms composed with [`Prism::then`].
+#[derive(Debug, Clone, Copy)]
+pub struct Then<A, B> {
+    first: A,
+    second: B,
+}
+
+impl<A, B> Prism for Then<A, B>
+where
+    A: Prism,
+    A::Focus: 'static,
+    B: Prism<Source = A::Focus>,
+{
+    type Source = A::Source;
+    type Focus = B::Focus;
+
+    fn preview<'a>(&self, source: &'a Self::Source) -> Option<&'a Self::Focus> {
+        self.second.preview(self.first.preview(source)?)
+    }
+
+    fn preview_mut<'a>(&self, source: &'a mut Self::Source) -> Option<&'a mut Self::Focus> {
+        self.second.preview_mut(self.first.preview_mut(source)?)
+    }
+
+    fn extract(&self, source: Self::Source) -> Result<Self::Focus, Self::Source> {
+        let middle = self.first.extract(source)?;
+        self.second
+            .extract(middle)
+            .map_err(|middle| self.first.review(middle))
+    }
+
+    fn review(&self, focus: Self::Focus) -> Self::Source {
+        self.first.review(self.second.review(focus))
+    }
+}
@@ -0,0 +1,66 @@
+/// `project!` extracts the listed fields of a variant into a struct that
+/// has one field for each of them. Given a reference, the struct holds
+/// references to the fields, so several assertions can be written against
+/// one projection:
+///
+#[cfg_attr(not(feature = "test-only"), doc = "```")]
+#[cfg_attr(feature = "test-only", doc = "```ignore")]
+/// use unsafe_get::project;
+///
+/// #[derive(Debug)]
+/// enum ExampleEnum {
+///   Big { a: i32, b: String, c: bool, d: Vec<u8> },
+///   Small,
+/// }
+///
+/// let value = ExampleEnum::Big { a: 1, b: "foo".to_string(), c: true, d: vec![] };
+/// let big = project!(&value, ExampleEnum::Big, a, b, c);
+/// assert_eq!(big.a, &1);
+/// assert_eq!(big.b, "foo");
+/// assert!(big.c);
+/// ```
+///
+/// With the `derive` feature, the fields can be left out to project all
+/// fields of a variant. This needs a reference to a value of an enum that
+/// derives [`VariantRefs`](crate::VariantRefs), and returns its `..Ref`
+/// struct:
+///
+#[cfg_attr(all(feature = "derive", not(feature = "test-only")), doc = "```")]
+#[cfg_attr(
+    not(all(feature = "derive", not(feature = "test-only"))),
+    doc = "```ignore"
+)]
+/// use unsafe_get::{project, VariantRefs};
+///
+/// #[derive(Debug, VariantRefs)]
+/// enum ExampleEnum {
+///   Big { a: i32, b: String },
+///   Small,
+/// }
+///
+/// let value = ExampleEnum::Big { a: 1, b: "foo".to_string() };
+/// let big = project!(&value, ExampleEnum::Big);
+/// assert_eq!(big.a, &1);
+/// assert_eq!(big.b, "foo");
+/// ```
+///
+/// The struct implements `Debug`, `Clone` and `Copy` when the fields do.
+/// Like [`get!`](crate::get), `project!` panics if the value is constructed
+/// with a different constructor.
+#[macro_export]
+macro_rules! project {
+    ($value:expr, $constructor:path $(,)?) => {{
+        $crate::__test_only!("project!");
+        let value: &_ = $value;
+        $crate::get!(@parse [value, stringify!($value)] [] $constructor);
+        $crate::__private::project_variant!(value, $constructor)
+    }};
+    ($value:expr, $constructor:path, $($field:ident),+ $(,)?) => {{
+        #[allow(non_camel_case_types)]
+        #[derive(Debug, Clone, Copy)]
+        struct Projection<$($field),+> {
+            $($field: $field),+
+        }
+        $crate::get!($value, $constructor, |$($field),+| Projection { $($field),+ })
+    }};
+}
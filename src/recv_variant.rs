@@ -0,0 +1,91 @@
+/// `recv_variant!` receives the next message from a
+/// [`std::sync::mpsc::Receiver`], waiting at most for the given timeout, and
+/// binds the variables of a refutable pattern against that message in the
+/// current scope, like [`let_assert!`](crate::let_assert):
+///
+/// ```
+/// use std::{sync::mpsc::channel, thread, time::Duration};
+/// use unsafe_get::recv_variant;
+///
+/// #[derive(Debug)]
+/// enum Event {
+///   Started { id: u32 },
+///   Stopped,
+/// }
+///
+/// let (sender, receiver) = channel();
+/// thread::spawn(move || {
+///   sender.send(Event::Started { id: 42 }).unwrap();
+///   sender.send(Event::Stopped).unwrap();
+/// });
+/// recv_variant!(receiver, Duration::from_secs(1), Event::Started { id });
+/// assert_eq!(id, 42);
+/// recv_variant!(receiver, Duration::from_secs(1), Event::Stopped);
+/// ```
+///
+/// If the timeout elapses, the channel is disconnected or the message
+/// doesn't match the pattern, `recv_variant!` panics with a message saying
+/// so. Like with `assert!`, a format string and arguments can be passed in
+/// to add to that message.
+#[macro_export]
+macro_rules! recv_variant {
+    (@describe $pattern:pat, $timeout:expr, $received:expr) => {
+        match &$received {
+            ::core::result::Result::Ok(message) => format!(
+                "recv_variant!: expected pattern: {}, got {}",
+                stringify!($pattern),
+                $crate::get!(@actual message)
+            ),
+            ::core::result::Result::Err(::std::sync::mpsc::RecvTimeoutError::Timeout) => format!(
+                "recv_variant!: expected pattern: {}, but timed out after {:?}",
+                stringify!($pattern),
+                $timeout
+            ),
+            ::core::result::Result::Err(::std::sync::mpsc::RecvTimeoutError::Disconnected) => format!(
+                "recv_variant!: expected pattern: {}, but the channel is disconnected",
+                stringify!($pattern)
+            ),
+        }
+    };
+    (@fail $receiver:expr, $pattern:pat, $received:expr, $description:expr) => {
+        match &$received {
+            ::core::result::Result::Ok(message) => {
+                $crate::get!(@report [stringify!($receiver), stringify!($pattern)] message, $description)
+            }
+            ::core::result::Result::Err(_) => $crate::get!(
+                @report [stringify!($receiver), stringify!($pattern), ::core::option::Option::None, ::std::string::String::new()]
+                $description
+            ),
+        }
+    };
+    ($receiver:expr, $timeout:expr, $pattern:pat $(,)?) => {
+        let timeout = $timeout;
+        let received = $receiver.recv_timeout(timeout);
+        #[allow(irrefutable_let_patterns)]
+        let ::core::result::Result::Ok($pattern) = received else {
+            $crate::recv_variant!(
+                @fail $receiver,
+                $pattern,
+                received,
+                $crate::recv_variant!(@describe $pattern, timeout, received)
+            )
+        };
+    };
+    ($receiver:expr, $timeout:expr, $pattern:pat, $($message:tt)+) => {
+        let timeout = $timeout;
+        let received = $receiver.recv_timeout(timeout);
+        #[allow(irrefutable_let_patterns)]
+        let ::core::result::Result::Ok($pattern) = received else {
+            $crate::recv_variant!(
+                @fail $receiver,
+                $pattern,
+                received,
+                format!(
+                    "{}: {}",
+                    $crate::recv_variant!(@describe $pattern, timeout, received),
+                    format_args!($($message)+)
+                )
+            )
+        };
+    };
+}
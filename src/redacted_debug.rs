@@ -0,0 +1,63 @@
+use std::fmt;
+
+/// Formats a value like `Debug`, but with sensitive fields masked. The
+/// macros in this crate prefer it over `Debug` when describing values in
+/// panic messages, so that secrets like tokens or passwords don't end up in
+/// test output or CI logs.
+///
+/// It can be implemented with `#[derive(RedactedDebug)]` (with the `derive`
+/// feature enabled), which formats fields marked with `#[redact]` as
+/// `<redacted>`:
+///
+#[cfg_attr(not(feature = "test-only"), doc = "```should_panic")]
+#[cfg_attr(feature = "test-only", doc = "```ignore")]
+/// # #[cfg(feature = "derive")]
+/// # {
+/// use unsafe_get::{get, RedactedDebug};
+///
+/// #[derive(Debug, RedactedDebug)]
+/// enum Credentials {
+///   Token {
+///     user: String,
+///     #[redact]
+///     token: String,
+///   },
+///   Anonymous,
+/// }
+///
+/// let credentials = Credentials::Token {
+///   user: "alice".to_string(),
+///   token: "secret".to_string(),
+/// };
+/// // panics with "get!: expected enum constructor: Credentials::Anonymous,
+/// // got Token { user: \"alice\", token: <redacted> }"
+/// get!(credentials, Credentials::Anonymous);
+/// # }
+/// # #[cfg(not(feature = "derive"))]
+/// # panic!();
+/// ```
+///
+/// Fields that aren't redacted are formatted with their `Debug`
+/// implementation.
+pub trait RedactedDebug {
+    /// Formats `self` with sensitive fields masked.
+    fn fmt_redacted(&self, formatter: &mut fmt::Formatter) -> fmt::Result;
+}
+
+impl<T: RedactedDebug + ?Sized> RedactedDebug for &T {
+    fn fmt_redacted(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        (**self).fmt_redacted(formatter)
+    }
+}
+
+impl<T: RedactedDebug + ?Sized> RedactedDebug for &mut T {
+    fn fmt_redacted(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        (**self).fmt_redacted(formatter)
+    }
+}
+
+impl<T: RedactedDebug + ?Sized> RedactedDebug for Box<T> {
+    fn fmt_redacted(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        (**self).fmt_redacted(formatter)
+    }
+}
@@ -0,0 +1,29 @@
+/// `set_field!` overwrites fields of a variant behind a mutable reference:
+///
+#[cfg_attr(not(feature = "test-only"), doc = "```")]
+#[cfg_attr(feature = "test-only", doc = "```ignore")]
+/// use unsafe_get::set_field;
+///
+/// #[derive(Debug, PartialEq)]
+/// enum ExampleEnum {
+///   Foo { a: i32, b: i32, c: i32 },
+///   Bar,
+/// }
+///
+/// let mut value = ExampleEnum::Foo { a: 1, b: 2, c: 3 };
+/// set_field!(&mut value, ExampleEnum::Foo, a = 7);
+/// set_field!(&mut value, ExampleEnum::Foo, b = 8, c = 9);
+/// assert_eq!(value, ExampleEnum::Foo { a: 7, b: 8, c: 9 });
+/// ```
+///
+/// Like [`get_mut!`](crate::get_mut), `set_field!` panics if the value is
+/// constructed with a different constructor.
+#[macro_export]
+macro_rules! set_field {
+    ($value:expr, $constructor:path, $($field:tt = $new_value:expr),+ $(,)?) => {{
+        let value: &mut _ = $value;
+        $(
+            *$crate::get_mut!(@named [stringify!($value)] &mut *value, $constructor, $field) = $new_value;
+        )+
+    }};
+}
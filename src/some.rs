@@ -0,0 +1,50 @@
+/// `some!` unwraps an `Option`. If it's `None`, `some!` panics with a
+/// message containing the unwrapped expression:
+///
+/// ```
+/// use unsafe_get::some;
+///
+/// let values = vec![1, 2, 3];
+/// assert_eq!(some!(values.first()), &1);
+/// ```
+///
+/// ```should_panic
+/// use unsafe_get::some;
+///
+/// let values: Vec<i32> = vec![];
+/// // panics with "some!: expected `values.first()` to be Some(_), got None"
+/// some!(values.first());
+/// ```
+///
+/// Like with `assert!`, a format string and arguments can be passed in to
+/// add to the panic message.
+#[macro_export]
+macro_rules! some {
+    (@describe $option:expr) => {
+        format!(
+            "some!: expected `{}` to be Some(_), got None",
+            stringify!($option)
+        )
+    };
+    (@fail $option:expr, $description:expr) => {
+        $crate::get!(
+            @report [stringify!($option), "Some(_)", ::core::option::Option::Some("None".to_string()), "None".to_string()]
+            $description
+        )
+    };
+    ($option:expr $(,)?) => {{
+        match $option {
+            ::core::option::Option::Some(value) => value,
+            ::core::option::Option::None => $crate::some!(@fail $option, $crate::some!(@describe $option)),
+        }
+    }};
+    ($option:expr, $($message:tt)+) => {{
+        match $option {
+            ::core::option::Option::Some(value) => value,
+            ::core::option::Option::None => $crate::some!(
+                @fail $option,
+                format!("{}: {}", $crate::some!(@describe $option), format_args!($($message)+))
+            ),
+        }
+    }};
+}
@@ -0,0 +1,44 @@
+/// `static_assert_variant!` asserts at compile time that a constant value is
+/// constructed with the given constructor. It can be used wherever items are
+/// allowed:
+///
+/// ```
+/// use unsafe_get::static_assert_variant;
+///
+/// enum Mode {
+///   Strict,
+///   Lenient { retries: u32 },
+/// }
+///
+/// const MODES: [Mode; 2] = [Mode::Strict, Mode::Lenient { retries: 3 }];
+///
+/// static_assert_variant!(MODES[0], Mode::Strict);
+/// static_assert_variant!(MODES[1], Mode::Lenient);
+/// ```
+///
+/// If the constant is constructed with a different constructor, compilation
+/// fails:
+///
+/// ```compile_fail
+/// use unsafe_get::static_assert_variant;
+///
+/// enum Mode {
+///   Strict,
+///   Lenient { retries: u32 },
+/// }
+///
+/// static_assert_variant!(Mode::Lenient { retries: 3 }, Mode::Strict);
+/// ```
+#[macro_export]
+macro_rules! static_assert_variant {
+    ($value:expr, $constructor:path $(,)?) => {
+        const _: () = match &$value {
+            $constructor { .. } => (),
+            #[allow(unreachable_patterns)]
+            _ => ::core::panic!(::core::concat!(
+                "static_assert_variant!: expected enum constructor: ",
+                ::core::stringify!($constructor)
+            )),
+        };
+    };
+}
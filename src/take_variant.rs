@@ -0,0 +1,48 @@
+/// `take_variant!` moves a field out of a variant behind a mutable
+/// reference, leaving the field's `Default` value in its place:
+///
+#[cfg_attr(not(feature = "test-only"), doc = "```")]
+#[cfg_attr(feature = "test-only", doc = "```ignore")]
+/// use unsafe_get::take_variant;
+///
+/// #[derive(Debug, PartialEq)]
+/// enum State {
+///   Loaded { data: Vec<String> },
+///   Loading,
+/// }
+///
+/// let mut state = State::Loaded { data: vec!["foo".to_string()] };
+/// assert_eq!(take_variant!(&mut state, State::Loaded, data), vec!["foo".to_string()]);
+/// assert_eq!(state, State::Loaded { data: vec![] });
+/// ```
+///
+/// For fields that don't implement `Default`, a placeholder can be passed
+/// in as the last argument:
+///
+#[cfg_attr(not(feature = "test-only"), doc = "```")]
+#[cfg_attr(feature = "test-only", doc = "```ignore")]
+/// use unsafe_get::take_variant;
+///
+/// #[derive(Debug, PartialEq)]
+/// enum State {
+///   Loaded { data: Vec<String> },
+///   Loading,
+/// }
+///
+/// let mut state = State::Loaded { data: vec!["foo".to_string()] };
+/// let data = take_variant!(&mut state, State::Loaded, data, vec!["bar".to_string()]);
+/// assert_eq!(data, vec!["foo".to_string()]);
+/// assert_eq!(state, State::Loaded { data: vec!["bar".to_string()] });
+/// ```
+///
+/// Like [`get_mut!`](crate::get_mut), `take_variant!` panics if the value
+/// is constructed with a different constructor.
+#[macro_export]
+macro_rules! take_variant {
+    ($value:expr, $constructor:path, $field:tt $(,)?) => {
+        ::core::mem::take($crate::get_mut!($value, $constructor, $field))
+    };
+    ($value:expr, $constructor:path, $field:tt, $placeholder:expr $(,)?) => {
+        ::core::mem::replace($crate::get_mut!($value, $constructor, $field), $placeholder)
+    };
+}
@@ -0,0 +1,24 @@
+/// With the `test-only` feature enabled, `__test_only!` expands to a
+/// compile error outside of `cfg(test)`. The macros that are meant to be
+/// used in tests only invoke it, so that the feature can enforce that.
+#[cfg(feature = "test-only")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __test_only {
+    ($name:literal) => {
+        #[cfg(not(test))]
+        {
+            ::core::compile_error!(::core::concat!(
+                $name,
+                " is only allowed in tests (the `test-only` feature of unsafe_get is enabled)"
+            ));
+        }
+    };
+}
+
+#[cfg(not(feature = "test-only"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __test_only {
+    ($name:literal) => {};
+}
@@ -0,0 +1,39 @@
+/// `try_get!` works like [`get!`](crate::get), but returns an `Option`
+/// instead of panicking. It returns `Some` with the field if the value is
+/// constructed with the given constructor, and `None` otherwise:
+///
+/// ```
+/// use unsafe_get::try_get;
+///
+/// #[derive(Debug)]
+/// enum ExampleEnum {
+///   Foo { field: i32 },
+///   Bar { other_field: String },
+/// }
+///
+/// let value = ExampleEnum::Foo { field: 42 };
+/// assert_eq!(try_get!(value, ExampleEnum::Foo, field), Some(42));
+///
+/// let value = ExampleEnum::Bar { other_field: "foo".to_string() };
+/// assert_eq!(try_get!(value, ExampleEnum::Foo, field), None);
+/// ```
+///
+/// Like `get!`, `try_get!` looks through `Box`es, and through references to
+/// `Box`es, `Rc`s, `Arc`s and `Cow`s.
+#[macro_export]
+macro_rules! try_get {
+    ($value:expr, $constructor:path, ($($field:ident),+ $(,)?) $(,)?) => {{
+        match $crate::get!(@peel $value) {
+            $constructor { $($field,)+ .. } => ::core::option::Option::Some(($($field),+)),
+            #[allow(unreachable_patterns)]
+            _ => ::core::option::Option::None,
+        }
+    }};
+    ($value:expr, $constructor:path, $field:tt $(,)?) => {{
+        match $crate::get!(@peel $value) {
+            $constructor { $field: field, .. } => ::core::option::Option::Some(field),
+            #[allow(unreachable_patterns)]
+            _ => ::core::option::Option::None,
+        }
+    }};
+}
@@ -0,0 +1,43 @@
+/// `try_let!` works like [`let_assert!`](crate::let_assert), but instead of
+/// panicking it returns early with a [`PatternMismatch`](crate::PatternMismatch)
+/// error if the value doesn't match the pattern. The error is converted with
+/// `From::from`, so `try_let!` composes with tests and helpers that return
+/// `Result<_, Box<dyn Error>>` or similar error types:
+///
+/// ```
+/// use std::error::Error;
+/// use unsafe_get::try_let;
+///
+/// #[derive(Debug)]
+/// enum ExampleEnum {
+///   Foo { field: i32 },
+///   Bar,
+/// }
+///
+/// fn double_field(value: ExampleEnum) -> Result<i32, Box<dyn Error>> {
+///   try_let!(ExampleEnum::Foo { field } = value);
+///   Ok(field * 2)
+/// }
+///
+/// assert_eq!(double_field(ExampleEnum::Foo { field: 21 }).unwrap(), 42);
+/// assert_eq!(
+///   double_field(ExampleEnum::Bar).unwrap_err().to_string(),
+///   "expected `value` to match pattern: ExampleEnum::Foo { field }, got Bar"
+/// );
+/// ```
+#[macro_export]
+macro_rules! try_let {
+    ($pattern:pat = $value:expr $(,)?) => {
+        let value = $value;
+        #[allow(irrefutable_let_patterns)]
+        let $pattern = value else {
+            return ::core::result::Result::Err(::core::convert::From::from(
+                $crate::PatternMismatch {
+                    expression: stringify!($value),
+                    pattern: stringify!($pattern),
+                    actual: $crate::get!(@actual value),
+                },
+            ));
+        };
+    };
+}
@@ -0,0 +1,32 @@
+use std::error::Error;
+use std::fmt;
+
+/// The error returned by [`get_result!`](crate::get_result) when a value
+/// is constructed with a different constructor than the expected one. It's
+/// also the error of the `TryFrom` implementations generated by
+/// `#[derive(TryIntoVariant)]`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnexpectedVariant {
+    /// The expected constructor, as it was passed to the macro.
+    pub expected: &'static str,
+    /// The name of the variant of the value that didn't match. If it can't
+    /// be determined, because the value implements neither `Debug` nor
+    /// [`VariantName`](crate::VariantName), it's the same as `value`.
+    pub actual: String,
+    /// The value that didn't match, as it's shown in panic messages: its
+    /// `Debug` output, which depends on features like `pretty-debug`, or
+    /// the name of its type if it doesn't implement `Debug`.
+    pub value: String,
+}
+
+impl fmt::Display for UnexpectedVariant {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            formatter,
+            "expected enum constructor: {}, got {}",
+            self.expected, self.value
+        )
+    }
+}
+
+impl Error for UnexpectedVariant {}
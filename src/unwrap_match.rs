@@ -0,0 +1,52 @@
+/// `unwrap_match!` generalizes [`get!`](crate::get) to arbitrary patterns.
+/// It matches a value against a pattern (with an optional guard) and
+/// evaluates the given expression with the pattern's bindings:
+///
+#[cfg_attr(not(feature = "test-only"), doc = "```")]
+#[cfg_attr(feature = "test-only", doc = "```ignore")]
+/// use unsafe_get::unwrap_match;
+///
+/// #[derive(Debug)]
+/// enum ExampleEnum {
+///   Foo { a: i32, b: i32 },
+///   Bar,
+/// }
+///
+/// let value = ExampleEnum::Foo { a: 1, b: 2 };
+/// assert_eq!(unwrap_match!(value, ExampleEnum::Foo { a, b } if a > 0 => a + b), 3);
+/// ```
+///
+/// If the value doesn't match the pattern, `unwrap_match!` panics with a
+/// message containing the pattern and the value:
+///
+#[cfg_attr(not(feature = "test-only"), doc = "```should_panic")]
+#[cfg_attr(feature = "test-only", doc = "```ignore")]
+/// use unsafe_get::unwrap_match;
+///
+/// #[derive(Debug)]
+/// enum ExampleEnum {
+///   Foo { a: i32, b: i32 },
+///   Bar,
+/// }
+///
+/// let value = ExampleEnum::Foo { a: 1, b: 2 };
+/// unwrap_match!(value, ExampleEnum::Foo { a: 2, .. } => ()); // panics
+/// ```
+#[macro_export]
+macro_rules! unwrap_match {
+    ($value:expr, $pattern:pat $(if $guard:expr)? => $result:expr $(,)?) => {{
+        $crate::__test_only!("unwrap_match!");
+        match $value {
+            $pattern $(if $guard)? => $result,
+            #[allow(unreachable_patterns)]
+            value => $crate::get!(
+                @report [stringify!($value), stringify!($pattern $(if $guard)?)] value,
+                format!(
+                    "unwrap_match!: expected pattern: {}, got {}",
+                    stringify!($pattern $(if $guard)?),
+                    $crate::get!(@actual value)
+                )
+            ),
+        }
+    }};
+}
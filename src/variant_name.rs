@@ -0,0 +1,68 @@
+/// `variant_name!` returns the name of the constructor a value is
+/// constructed with, as a `String`. It's based on the `Debug` output of the
+/// value, so it works for all enums that implement `Debug`:
+///
+/// ```
+/// use unsafe_get::variant_name;
+///
+/// #[derive(Debug)]
+/// enum ExampleEnum {
+///   Foo { field: i32 },
+///   Pair(i32, i32),
+///   Done,
+/// }
+///
+/// assert_eq!(variant_name!(ExampleEnum::Foo { field: 42 }), "Foo");
+/// assert_eq!(variant_name!(&ExampleEnum::Pair(1, 2)), "Pair");
+/// assert_eq!(variant_name!(ExampleEnum::Done), "Done");
+/// ```
+///
+/// This relies on the `Debug` output starting with the name of the variant,
+/// which is the case for derived `Debug` implementations. The formatting
+/// stops after the name, so it's cheap even for huge values. For values that
+/// implement [`VariantName`](crate::VariantName), `variant_name!` uses that
+/// implementation instead.
+#[macro_export]
+macro_rules! variant_name {
+    ($value:expr $(,)?) => {{
+        use $crate::__private::{NameOfDebug as _, NameOfVariantName as _};
+        (&$crate::__private::NameOf(&$value)).variant_name()
+    }};
+}
+
+/// Returns the name of the constructor a value is constructed with. It can
+/// be implemented with `#[derive(VariantName)]` (with the `derive` feature
+/// enabled), which also works for enums that don't implement `Debug`.
+///
+/// The macros in this crate use it to describe values that don't implement
+/// `Debug` in panic messages, and [`variant_name!`](crate::variant_name)
+/// prefers it over parsing the `Debug` output.
+///
+/// The method is called `variant_name` instead of `name`, because enums
+/// often have a `name` method or field of their own, and an inherent method
+/// silently takes precedence over a trait method with the same name. There's
+/// no `kind_str` either, to avoid confusion with the `kind` method of
+/// `#[derive(VariantKind)]`, which returns a fieldless enum instead of a
+/// string.
+pub trait VariantName {
+    /// Returns the name of the constructor `self` is constructed with.
+    fn variant_name(&self) -> &'static str;
+}
+
+impl<T: VariantName + ?Sized> VariantName for &T {
+    fn variant_name(&self) -> &'static str {
+        (**self).variant_name()
+    }
+}
+
+impl<T: VariantName + ?Sized> VariantName for &mut T {
+    fn variant_name(&self) -> &'static str {
+        (**self).variant_name()
+    }
+}
+
+impl<T: VariantName + ?Sized> VariantName for Box<T> {
+    fn variant_name(&self) -> &'static str {
+        (**self).variant_name()
+    }
+}
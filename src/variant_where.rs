@@ -0,0 +1,61 @@
+/// `variant_where!` returns a predicate closure that checks whether a value
+/// matches the given pattern, optionally with a guard. Like with
+/// [`is_variant!`](crate::is_variant), the closure takes the value by
+/// reference, so it can be passed to APIs expecting an
+/// `impl Fn(&T) -> bool`:
+///
+/// ```
+/// use unsafe_get::variant_where;
+///
+/// #[derive(Debug)]
+/// enum Event {
+///   Retry { attempt: u32 },
+///   Done,
+/// }
+///
+/// let is_late_retry = variant_where!(Event::Retry { attempt } if *attempt > 2);
+/// assert!(is_late_retry(&Event::Retry { attempt: 3 }));
+/// assert!(!is_late_retry(&Event::Retry { attempt: 1 }));
+/// assert!(!is_late_retry(&Event::Done));
+/// ```
+///
+/// Since the value is matched by reference, bindings in the pattern are
+/// references, too. The closure captures variables used in the guard by
+/// reference; use `move` before the pattern to capture them by value:
+///
+/// ```
+/// use unsafe_get::variant_where;
+///
+/// #[derive(Debug)]
+/// enum Event {
+///   Retry { attempt: u32 },
+///   Done,
+/// }
+///
+/// fn retries_after(limit: u32) -> impl Fn(&Event) -> bool {
+///   variant_where!(move Event::Retry { attempt } if *attempt > limit)
+/// }
+///
+/// assert!(retries_after(2)(&Event::Retry { attempt: 3 }));
+/// ```
+#[macro_export]
+macro_rules! variant_where {
+    (move $(|)? $($pattern:pat)|+ $(if $guard:expr)? $(,)?) => {
+        move |value: &_| {
+            #[allow(unreachable_patterns)]
+            match value {
+                $($pattern)|+ $(if $guard)? => true,
+                _ => false,
+            }
+        }
+    };
+    ($(|)? $($pattern:pat)|+ $(if $guard:expr)? $(,)?) => {
+        |value: &_| {
+            #[allow(unreachable_patterns)]
+            match value {
+                $($pattern)|+ $(if $guard)? => true,
+                _ => false,
+            }
+        }
+    };
+}
@@ -0,0 +1,67 @@
+mod common;
+
+use common::assert_panics_with;
+use std::env;
+use unsafe_get::assert_fields_eq;
+
+#[derive(Debug)]
+enum Enum {
+    Foo { a: i32, b: String, c: bool },
+    Bar(i32, String, bool),
+}
+
+fn foo() -> Enum {
+    Enum::Foo {
+        a: 1,
+        b: "foo".to_string(),
+        c: true,
+    }
+}
+
+#[test]
+fn compares_only_the_listed_fields() {
+    assert_fields_eq!(foo(), Enum::Foo { b: "foo" });
+    assert_fields_eq!(&foo(), Enum::Foo { a: 1, c: true, .. });
+    assert_fields_eq!(
+        Enum::Bar(1, "bar".to_string(), false),
+        Enum::Bar(1, _, false)
+    );
+    assert_fields_eq!(Enum::Bar(1, "bar".to_string(), false), Enum::Bar(_, "bar"));
+}
+
+#[test]
+#[should_panic(
+    expected = "assert_fields_eq!: fields of Enum::Foo differ\n  field `a`:\n     left: 1\n    right: 2\n  field `b`:\n     left: \"foo\"\n    right: \"bar\""
+)]
+fn lists_differing_fields_separately() {
+    // The `color` feature colors the differing values.
+    env::set_var("NO_COLOR", "1");
+    assert_fields_eq!(
+        foo(),
+        Enum::Foo {
+            a: 2,
+            b: "bar",
+            c: true
+        }
+    );
+}
+
+#[test]
+#[should_panic(
+    expected = "assert_fields_eq!: fields of Enum::Bar differ\n  field `0`:\n     left: 1\n    right: 2\n  field `2`:\n     left: false\n    right: true"
+)]
+fn lists_differing_fields_of_tuple_variants() {
+    // The `color` feature colors the differing values.
+    env::set_var("NO_COLOR", "1");
+    assert_fields_eq!(
+        Enum::Bar(1, "bar".to_string(), false),
+        Enum::Bar(2, _, true)
+    );
+}
+
+#[test]
+fn panics_for_other_constructors() {
+    assert_panics_with("assert_fields_eq!: expected enum constructor: Enum::Bar, got Foo { a: 1, b: \"foo\", c: true }", || {
+        assert_fields_eq!(foo(), Enum::Bar(1, _, _));
+    });
+}
@@ -0,0 +1,26 @@
+use unsafe_get::assert_same_variant;
+
+#[allow(dead_code)]
+#[derive(Debug)]
+enum Enum {
+    Foo { foo: i32 },
+    Bar(bool),
+}
+
+#[test]
+fn passes_for_values_of_the_same_variant() {
+    assert_same_variant!(Enum::Foo { foo: 1 }, Enum::Foo { foo: 2 });
+    assert_same_variant!(&Enum::Bar(true), &Enum::Bar(false));
+}
+
+#[test]
+#[should_panic(expected = "assert_same_variant!: expected the same variant, got Foo and Bar")]
+fn panics_for_values_of_different_variants() {
+    assert_same_variant!(Enum::Foo { foo: 1 }, Enum::Bar(true));
+}
+
+#[test]
+#[should_panic(expected = "assert_same_variant!: expected the same variant, got Bar and Foo")]
+fn compares_the_referenced_values() {
+    assert_same_variant!(&Enum::Bar(true), &Enum::Foo { foo: 1 });
+}
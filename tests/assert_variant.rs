@@ -0,0 +1,52 @@
+mod common;
+
+use common::assert_panics_with;
+use std::panic::catch_unwind;
+use unsafe_get::assert_variant;
+
+#[allow(dead_code)]
+#[derive(Debug)]
+enum Enum {
+    Foo { foo: i32 },
+    Bar,
+}
+
+#[test]
+fn passes_for_the_expected_constructor() {
+    assert_variant!(Enum::Foo { foo: 42 }, Enum::Foo);
+    assert_variant!(&Enum::Bar, Enum::Bar);
+}
+
+#[test]
+fn panics_for_other_constructors() {
+    assert_panics_with(
+        "assert_variant!: expected enum constructor: Enum::Bar, got Foo { foo: 42 }",
+        || {
+            assert_variant!(Enum::Foo { foo: 42 }, Enum::Bar);
+        },
+    );
+}
+
+#[test]
+#[should_panic(
+    expected = "assert_variant!: expected enum constructor: Enum::Foo, got Bar: in case 3"
+)]
+fn appends_custom_messages() {
+    assert_variant!(Enum::Bar, Enum::Foo, "in case {}", 3);
+}
+
+#[test]
+fn prefixes_panic_messages_with_the_location() {
+    // The `color` feature appends a colored diff to the panic messages.
+    std::env::set_var("NO_COLOR", "1");
+    let line = line!() + 1;
+    let payload = catch_unwind(|| assert_variant!(Enum::Bar, Enum::Foo)).unwrap_err();
+    assert_eq!(
+        payload.downcast_ref::<String>().unwrap(),
+        &format!(
+            "{}:{}:35: assert_variant!: expected enum constructor: Enum::Foo, got Bar",
+            file!(),
+            line
+        )
+    );
+}
@@ -0,0 +1,57 @@
+mod common;
+
+use common::assert_panics_with;
+use std::env;
+use unsafe_get::assert_variant_eq;
+
+#[derive(Debug)]
+enum Enum {
+    Foo { a: i32, b: String, c: bool },
+    Bar(i32, i32),
+}
+
+fn foo() -> Enum {
+    Enum::Foo {
+        a: 1,
+        b: "foo".to_string(),
+        c: true,
+    }
+}
+
+#[test]
+fn passes_when_the_listed_fields_are_equal() {
+    assert_variant_eq!(foo(), Enum::Foo { b: "foo", .. });
+    assert_variant_eq!(
+        &foo(),
+        Enum::Foo {
+            a: 1,
+            b: "foo",
+            c: true
+        }
+    );
+    assert_variant_eq!(Enum::Bar(1, 2), Enum::Bar { 1: 2, .. });
+}
+
+#[test]
+#[should_panic(
+    expected = "assert_variant_eq!: fields of Enum::Foo differ\n  field `a`:\n     left: 1\n    right: 2\n  field `c`:\n     left: true\n    right: false"
+)]
+fn lists_all_differing_fields() {
+    // The `color` feature colors the differing values.
+    env::set_var("NO_COLOR", "1");
+    assert_variant_eq!(
+        foo(),
+        Enum::Foo {
+            a: 2,
+            b: "foo",
+            c: false
+        }
+    );
+}
+
+#[test]
+fn panics_for_other_constructors() {
+    assert_panics_with("assert_variant_eq!: expected enum constructor: Enum::Bar, got Foo { a: 1, b: \"foo\", c: true }", || {
+        assert_variant_eq!(foo(), Enum::Bar { 0: 1, .. });
+    });
+}
@@ -0,0 +1,35 @@
+mod common;
+
+use common::assert_panics_with;
+use unsafe_get::assert_variant_ne;
+
+#[allow(dead_code)]
+#[derive(Debug)]
+enum Enum {
+    Foo { foo: i32 },
+    Bar,
+}
+
+#[test]
+fn passes_for_other_constructors() {
+    assert_variant_ne!(Enum::Foo { foo: 42 }, Enum::Bar);
+    assert_variant_ne!(&Enum::Bar, Enum::Foo);
+}
+
+#[test]
+fn panics_for_the_given_constructor() {
+    assert_panics_with(
+        "assert_variant_ne!: expected enum constructor other than Enum::Foo, got Foo { foo: 42 }",
+        || {
+            assert_variant_ne!(Enum::Foo { foo: 42 }, Enum::Foo);
+        },
+    );
+}
+
+#[test]
+#[should_panic(
+    expected = "assert_variant_ne!: expected enum constructor other than Enum::Bar, got Bar: in case 3"
+)]
+fn appends_custom_messages() {
+    assert_variant_ne!(Enum::Bar, Enum::Bar, "in case {}", 3);
+}
@@ -0,0 +1,83 @@
+#![cfg(feature = "color")]
+
+use std::env;
+use std::panic::{catch_unwind, UnwindSafe};
+use std::sync::Mutex;
+use unsafe_get::{assert_variant, assert_variant_eq, get};
+
+#[derive(Debug)]
+enum Enum {
+    Foo { foo: i32 },
+    Bar,
+}
+
+/// The tests share the process environment, so they take turns setting
+/// `NO_COLOR`.
+static ENVIRONMENT: Mutex<()> = Mutex::new(());
+
+/// Runs `f` with `NO_COLOR` set to the given value (or unset) and returns
+/// its panic message, without the location that it starts with.
+fn panic_message(no_color: Option<&str>, f: impl FnOnce() + UnwindSafe) -> String {
+    let _guard = ENVIRONMENT
+        .lock()
+        .unwrap_or_else(|error| error.into_inner());
+    match no_color {
+        Some(value) => env::set_var("NO_COLOR", value),
+        None => env::remove_var("NO_COLOR"),
+    }
+    let payload = catch_unwind(f).unwrap_err();
+    let message = payload.downcast_ref::<String>().unwrap();
+    let (location, message) = message.split_once(": ").unwrap();
+    assert!(location.starts_with(file!()), "{}", location);
+    message.to_string()
+}
+
+const GET_MISMATCH: &str =
+    "get!: expected enum constructor: Enum::Foo, got Bar while matching `Enum::Bar`";
+
+#[test]
+fn appends_a_colored_diff_to_mismatches() {
+    assert_eq!(
+        panic_message(None, || get!(Enum::Bar, Enum::Foo)),
+        format!(
+            "{}\nDiff < actual / expected > :\n\x1b[31m<Bar\x1b[0m\n\x1b[32m>Enum::Foo\x1b[0m",
+            GET_MISMATCH
+        )
+    );
+    assert_eq!(
+        panic_message(None, || assert_variant!(Enum::Bar, Enum::Foo)),
+        "assert_variant!: expected enum constructor: Enum::Foo, got Bar\nDiff < actual / expected > :\n\x1b[31m<Bar\x1b[0m\n\x1b[32m>Enum::Foo\x1b[0m"
+    );
+}
+
+#[test]
+fn colors_differing_fields() {
+    assert!(panic_message(None, || assert_variant_eq!(
+        Enum::Foo { foo: 1 },
+        Enum::Foo { foo: 2 }
+    ))
+    .starts_with(
+        "assert_variant_eq!: fields of Enum::Foo differ\n  field `foo`:\n     left: \x1b[31m1\x1b[0m\n    right: \x1b[32m2\x1b[0m\n"
+    ));
+}
+
+#[test]
+fn doesnt_color_if_no_color_is_set() {
+    assert_eq!(
+        panic_message(Some("1"), || get!(Enum::Bar, Enum::Foo)),
+        GET_MISMATCH
+    );
+    assert_eq!(
+        panic_message(Some("1"), || assert_variant_eq!(
+            Enum::Foo { foo: 1 },
+            Enum::Foo { foo: 2 }
+        )),
+        "assert_variant_eq!: fields of Enum::Foo differ\n  field `foo`:\n     left: 1\n    right: 2"
+    );
+}
+
+#[test]
+fn colors_if_no_color_is_empty() {
+    assert!(panic_message(Some(""), || get!(Enum::Bar, Enum::Foo))
+        .starts_with(&format!("{}\nDiff < actual / expected > :\n", GET_MISMATCH)));
+}
@@ -0,0 +1,39 @@
+//! Helpers shared by the tests. The panic messages contain `Debug` output,
+//! which the `pretty-debug` feature spreads over multiple lines, so these
+//! helpers compare it independently of its format.
+
+#![allow(dead_code)]
+
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+/// Runs `f` and asserts that it panics with a message that contains
+/// `expected`, like `#[should_panic(expected = "...")]` does, but ignoring
+/// the differences between `{:?}` and `{:#?}`.
+#[track_caller]
+pub fn assert_panics_with<R>(expected: &str, f: impl FnOnce() -> R) {
+    let payload = match catch_unwind(AssertUnwindSafe(f)) {
+        Ok(_) => panic!("expected a panic with {:?}", expected),
+        Err(payload) => payload,
+    };
+    let message = match payload.downcast_ref::<String>() {
+        Some(message) => message.as_str(),
+        None => payload.downcast_ref::<&str>().unwrap(),
+    };
+    assert!(
+        squash(message).contains(&squash(expected)),
+        "panic message {:?} doesn't contain {:?}",
+        message,
+        expected
+    );
+}
+
+/// Removes the whitespace and the trailing commas that `{:#?}` adds to
+/// `Debug` output, so that it can be compared with the output of `{:?}`.
+pub fn squash(text: &str) -> String {
+    text.chars()
+        .filter(|char| !char.is_whitespace())
+        .collect::<String>()
+        .replace(",}", "}")
+        .replace(",]", "]")
+        .replace(",)", ")")
+}
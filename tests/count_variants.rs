@@ -0,0 +1,40 @@
+use unsafe_get::count_variants;
+
+#[allow(dead_code)]
+#[derive(Debug)]
+enum Enum {
+    Foo { foo: i32 },
+    Pair(i32, i32),
+    Bar,
+}
+
+fn values() -> Vec<Enum> {
+    vec![
+        Enum::Foo { foo: 1 },
+        Enum::Pair(1, 2),
+        Enum::Foo { foo: 2 },
+        Enum::Bar,
+    ]
+}
+
+#[test]
+fn counts_matching_constructors() {
+    let values = values();
+    assert_eq!(count_variants!(&values, Enum::Foo), 2);
+    assert_eq!(count_variants!(&values, Enum::Pair), 1);
+    assert_eq!(count_variants!(values, Enum::Bar), 1);
+}
+
+#[test]
+fn works_with_iterators() {
+    assert_eq!(count_variants!(values().iter().skip(1), Enum::Foo), 1);
+    assert_eq!(count_variants!(std::iter::empty::<Enum>(), Enum::Foo), 0);
+}
+
+#[test]
+fn counts_matching_patterns() {
+    let values = values();
+    assert_eq!(count_variants!(&values, Enum::Foo { foo: 2 }), 1);
+    assert_eq!(count_variants!(&values, Enum::Pair(1, _) | Enum::Bar), 2);
+    assert_eq!(count_variants!(&values, Enum::Foo { foo } if *foo > 1), 1);
+}
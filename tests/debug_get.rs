@@ -0,0 +1,45 @@
+mod common;
+
+use common::assert_panics_with;
+use unsafe_get::debug_get;
+
+#[allow(dead_code)]
+#[derive(Debug)]
+enum Enum {
+    Foo { foo: i32, bar: bool },
+    Pair(i32, i32),
+}
+
+#[test]
+fn returns_enum_fields() {
+    let value = Enum::Foo { foo: 42, bar: true };
+    assert_eq!(unsafe { debug_get!(&value, Enum::Foo, foo) }, &42);
+    assert_eq!(unsafe { debug_get!(Enum::Pair(1, 2), Enum::Pair, 1) }, 2);
+    assert_eq!(
+        unsafe { debug_get!(value, Enum::Foo, (foo, bar)) },
+        (42, true)
+    );
+}
+
+// Runs in release builds too (`cargo test --release`), where the arguments
+// are passed on to `get_unchecked!`.
+#[test]
+fn accepts_trailing_commas() {
+    let value = Enum::Foo { foo: 42, bar: true };
+    assert_eq!(unsafe { debug_get!(&value, Enum::Foo, foo,) }, &42);
+    assert_eq!(
+        unsafe { debug_get!(value, Enum::Foo, (foo, bar,),) },
+        (42, true)
+    );
+}
+
+#[test]
+#[cfg(debug_assertions)]
+fn panics_in_debug_builds() {
+    assert_panics_with(
+        "get!: expected enum constructor: Enum::Foo, got Pair(1, 2)",
+        || {
+            unsafe { debug_get!(Enum::Pair(1, 2), Enum::Foo, foo) };
+        },
+    );
+}
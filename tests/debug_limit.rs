@@ -0,0 +1,58 @@
+use std::env;
+use std::fmt;
+use std::panic::catch_unwind;
+use unsafe_get::get;
+
+#[allow(dead_code)]
+enum Enum {
+    Foo { foo: i32 },
+    Bar { bar: Vec<u8> },
+}
+
+fn panic_message(value: Enum) -> String {
+    let payload = catch_unwind(|| get!(value, Enum::Foo, foo)).unwrap_err();
+    payload.downcast_ref::<String>().unwrap().clone()
+}
+
+// `Debug` is implemented by hand, so that the truncated output doesn't
+// depend on the `pretty-debug` feature.
+impl fmt::Debug for Enum {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Enum::Foo { foo: value } => write!(formatter, "Foo {{ foo: {:?} }}", value),
+            Enum::Bar { bar: value } => write!(formatter, "Bar {{ bar: {:?} }}", value),
+        }
+    }
+}
+
+fn huge() -> Enum {
+    Enum::Bar {
+        bar: vec![0; 100_000],
+    }
+}
+
+// The tests share the process environment, so they're run in one test.
+#[test]
+fn truncates_huge_debug_output() {
+    // The `color` feature appends a colored diff to the panic messages.
+    env::set_var("NO_COLOR", "1");
+    env::remove_var("UNSAFE_GET_DEBUG_LIMIT");
+    let message = panic_message(huge());
+    assert!(message.len() < 11_000);
+    assert!(message.ends_with(
+        "... (truncated after 10000 bytes, set UNSAFE_GET_DEBUG_LIMIT=0 to see the full output) while matching `value`"
+    ));
+
+    env::set_var("UNSAFE_GET_DEBUG_LIMIT", "15");
+    assert_eq!(
+        panic_message(huge()),
+        "tests/debug_limit.rs:13:35: get!: expected enum constructor: Enum::Foo, got Bar { bar: [0, ... (truncated after 15 bytes, set UNSAFE_GET_DEBUG_LIMIT=0 to see the full output) while matching `value`"
+    );
+    assert_eq!(
+        panic_message(Enum::Bar { bar: vec![] }),
+        "tests/debug_limit.rs:13:35: get!: expected enum constructor: Enum::Foo, got Bar { bar: [] } while matching `value`"
+    );
+
+    env::set_var("UNSAFE_GET_DEBUG_LIMIT", "0");
+    assert!(panic_message(huge()).ends_with("0, 0] } while matching `value`"));
+}
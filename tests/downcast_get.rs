@@ -0,0 +1,66 @@
+mod common;
+
+use common::assert_panics_with;
+use std::any::Any;
+use unsafe_get::downcast_get;
+
+#[allow(dead_code)]
+#[derive(Debug)]
+enum Enum {
+    Foo { foo: i32, bar: bool },
+    Pair(i32, i32),
+}
+
+struct NoDebug;
+
+#[allow(dead_code)]
+enum NoDebugEnum {
+    A(NoDebug),
+    B,
+}
+
+#[test]
+fn returns_fields_of_boxed_values() {
+    let value: Box<dyn Any> = Box::new(Enum::Foo { foo: 42, bar: true });
+    assert_eq!(downcast_get!(value, Enum::Foo, foo), &42);
+    assert_eq!(downcast_get!(value, Enum::Foo, (foo, bar)), (&42, &true));
+    downcast_get!(value, Enum::Foo);
+}
+
+#[test]
+fn returns_fields_of_references() {
+    let value = Enum::Pair(1, 2);
+    let any: &dyn Any = &value;
+    assert_eq!(downcast_get!(any, Enum::Pair, 1), &2);
+    let send: Box<dyn Any + Send> = Box::new(Enum::Pair(3, 4));
+    assert_eq!(downcast_get!(send, Enum::Pair, 0), &3);
+}
+
+#[test]
+#[should_panic(
+    expected = "downcast_get!: expected a value of type downcast_get::Enum, got a value of another type"
+)]
+fn panics_for_other_types() {
+    let value: Box<dyn Any> = Box::new(42);
+    downcast_get!(value, Enum::Pair, 0);
+}
+
+#[test]
+fn panics_for_other_constructors() {
+    assert_panics_with(
+        "downcast_get!: expected enum constructor: Enum::Pair, got Foo { foo: 42, bar: true }",
+        || {
+            let value: Box<dyn Any> = Box::new(Enum::Foo { foo: 42, bar: true });
+            downcast_get!(value, Enum::Pair, 0);
+        },
+    );
+}
+
+#[test]
+#[should_panic(
+    expected = "downcast_get!: expected enum constructor: NoDebugEnum::A, got a value of type downcast_get::NoDebugEnum (which doesn't implement Debug)"
+)]
+fn works_for_values_without_debug() {
+    let value: Box<dyn Any> = Box::new(NoDebugEnum::B);
+    downcast_get!(value, NoDebugEnum::A);
+}
@@ -0,0 +1,49 @@
+use unsafe_get::drain_variant;
+
+#[derive(Debug, PartialEq)]
+enum Enum {
+    Foo { foo: i32, bar: bool },
+    Pair(i32, i32),
+}
+
+fn values() -> Vec<Enum> {
+    vec![
+        Enum::Foo { foo: 1, bar: true },
+        Enum::Pair(2, 3),
+        Enum::Foo { foo: 4, bar: false },
+    ]
+}
+
+#[test]
+fn removes_and_returns_fields() {
+    let mut values = values();
+    assert_eq!(drain_variant!(&mut values, Enum::Foo, foo), vec![1, 4]);
+    assert_eq!(values, vec![Enum::Pair(2, 3)]);
+}
+
+#[test]
+fn returns_multiple_fields() {
+    let mut values = values();
+    assert_eq!(
+        drain_variant!(&mut values, Enum::Foo, (foo, bar)),
+        vec![(1, true), (4, false)]
+    );
+}
+
+#[test]
+fn returns_the_elements_without_fields() {
+    let mut values = values();
+    assert_eq!(
+        drain_variant!(&mut values, Enum::Pair),
+        vec![Enum::Pair(2, 3)]
+    );
+    assert_eq!(
+        values,
+        vec![
+            Enum::Foo { foo: 1, bar: true },
+            Enum::Foo { foo: 4, bar: false }
+        ]
+    );
+    assert!(drain_variant!(&mut values, Enum::Pair, 0).is_empty());
+    assert_eq!(values.len(), 2);
+}
@@ -0,0 +1,84 @@
+mod common;
+
+use common::assert_panics_with;
+use std::error::Error;
+use std::fmt;
+use unsafe_get::expect_error_variant;
+
+#[allow(dead_code)]
+#[derive(Debug)]
+enum Inner {
+    Foo { foo: i32, bar: bool },
+    Pair(i32, i32),
+}
+
+impl fmt::Display for Inner {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "inner")
+    }
+}
+
+impl Error for Inner {}
+
+#[derive(Debug)]
+struct Outer(Inner);
+
+#[allow(dead_code)]
+#[derive(Debug)]
+enum Other {
+    Other,
+}
+
+impl fmt::Display for Other {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "other")
+    }
+}
+
+impl Error for Other {}
+
+impl fmt::Display for Outer {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "outer")
+    }
+}
+
+impl Error for Outer {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.0)
+    }
+}
+
+#[test]
+fn finds_errors_in_the_source_chain() {
+    let error = Outer(Inner::Foo { foo: 42, bar: true });
+    assert_eq!(expect_error_variant!(error, Inner::Foo, foo), &42);
+    assert_eq!(
+        expect_error_variant!(error, Inner::Foo, (foo, bar)),
+        (&42, &true)
+    );
+    expect_error_variant!(error, Inner::Foo);
+}
+
+#[test]
+fn matches_the_error_itself() {
+    let error: Box<dyn Error> = Box::new(Inner::Pair(1, 2));
+    assert_eq!(expect_error_variant!(*error, Inner::Pair, 1), &2);
+}
+
+#[test]
+fn panics_for_other_constructors() {
+    assert_panics_with("expect_error_variant!: expected enum constructor: Inner::Pair, got Foo { foo: 42, bar: true }", || {
+        let error = Outer(Inner::Foo { foo: 42, bar: true });
+        expect_error_variant!(error, Inner::Pair, 0);
+    });
+}
+
+#[test]
+#[should_panic(
+    expected = "expect_error_variant!: no error of type expect_error_variant::Other in source chain: outer -> inner"
+)]
+fn panics_without_matching_errors_in_the_source_chain() {
+    let error = Outer(Inner::Pair(1, 2));
+    expect_error_variant!(error, Other::Other);
+}
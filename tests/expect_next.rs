@@ -0,0 +1,55 @@
+mod common;
+
+use common::assert_panics_with;
+use unsafe_get::expect_next;
+
+#[allow(dead_code)]
+#[derive(Debug)]
+enum Enum {
+    Foo { foo: i32, bar: bool },
+    Pair(i32, i32),
+    Bar,
+}
+
+fn values() -> Vec<Enum> {
+    vec![Enum::Foo { foo: 1, bar: true }, Enum::Pair(2, 3), Enum::Bar]
+}
+
+#[test]
+fn binds_fields_of_consecutive_items() {
+    let mut iterator = values().into_iter();
+    expect_next!(iterator, Enum::Foo { foo, bar });
+    assert_eq!((foo, bar), (1, true));
+    expect_next!(iterator, Enum::Pair(a, b));
+    assert_eq!((a, b), (2, 3));
+    expect_next!(iterator, Enum::Bar);
+    assert!(iterator.next().is_none());
+}
+
+#[test]
+fn works_with_iterators_over_references() {
+    let values = values();
+    let mut iterator = values.iter();
+    expect_next!(iterator, Enum::Foo { foo, .. });
+    assert_eq!(foo, &1);
+}
+
+#[test]
+fn panics_for_mismatching_items() {
+    assert_panics_with(
+        "expect_next!: expected pattern: Enum::Bar, got Pair(2, 3)",
+        || {
+            let mut iterator = values().into_iter().skip(1);
+            expect_next!(iterator, Enum::Bar);
+        },
+    );
+}
+
+#[test]
+#[should_panic(
+    expected = "expect_next!: expected pattern: Enum::Bar, but the iterator is exhausted: in step 3"
+)]
+fn panics_for_exhausted_iterators() {
+    let mut iterator = std::iter::empty::<Enum>();
+    expect_next!(iterator, Enum::Bar, "in step {}", 3);
+}
@@ -0,0 +1,73 @@
+mod common;
+
+use common::assert_panics_with;
+use std::panic::panic_any;
+use unsafe_get::expect_panic_variant;
+
+#[allow(dead_code)]
+#[derive(Debug)]
+enum Enum {
+    Foo { foo: i32, bar: String },
+    Pair(i32, i32),
+}
+
+#[test]
+fn returns_panic_messages() {
+    assert_eq!(expect_panic_variant!(|| panic!("static")), "static");
+    assert_eq!(
+        expect_panic_variant!(|| panic!("formatted {}", 42)),
+        "formatted 42"
+    );
+}
+
+#[test]
+fn returns_fields_of_panic_payloads() {
+    let foo = || {
+        panic_any(Enum::Foo {
+            foo: 42,
+            bar: "bar".to_string(),
+        })
+    };
+    assert_eq!(expect_panic_variant!(foo, Enum::Foo, bar), "bar");
+    assert_eq!(
+        expect_panic_variant!(foo, Enum::Foo, (foo, bar)),
+        (42, "bar".to_string())
+    );
+    expect_panic_variant!(foo, Enum::Foo);
+    assert_eq!(
+        expect_panic_variant!(|| panic_any(Enum::Pair(1, 2)), Enum::Pair, 1),
+        2
+    );
+}
+
+#[test]
+#[should_panic(expected = "expect_panic_variant!: expected the closure to panic, but it returned")]
+fn panics_if_the_closure_returns() {
+    expect_panic_variant!(|| 42);
+}
+
+#[test]
+fn panics_for_other_constructors() {
+    assert_panics_with(
+        "expect_panic_variant!: expected enum constructor: Enum::Foo, got Pair(1, 2)",
+        || {
+            expect_panic_variant!(|| panic_any(Enum::Pair(1, 2)), Enum::Foo, foo);
+        },
+    );
+}
+
+#[test]
+#[should_panic(
+    expected = "expect_panic_variant!: expected a payload of type expect_panic_variant::Enum, got the message \"boom 1\""
+)]
+fn panics_for_other_payload_types() {
+    expect_panic_variant!(|| panic!("boom {}", 1), Enum::Pair, 0);
+}
+
+#[test]
+#[should_panic(
+    expected = "expect_panic_variant!: expected a panic message, got a payload of another type"
+)]
+fn panics_for_payloads_without_messages() {
+    expect_panic_variant!(|| panic_any(Enum::Pair(1, 2)));
+}
@@ -0,0 +1,54 @@
+mod common;
+
+use common::assert_panics_with;
+use unsafe_get::expect_single;
+
+#[derive(Debug, PartialEq)]
+enum Enum {
+    Foo { foo: i32, bar: bool },
+    Pair(i32, i32),
+}
+
+fn values() -> Vec<Enum> {
+    vec![
+        Enum::Foo { foo: 1, bar: true },
+        Enum::Pair(2, 3),
+        Enum::Foo { foo: 4, bar: false },
+    ]
+}
+
+#[test]
+fn returns_the_field_of_the_single_match() {
+    assert_eq!(expect_single!(values(), Enum::Pair, 1), 3);
+    let values = values();
+    assert_eq!(expect_single!(&values, Enum::Pair, 0), &2);
+}
+
+#[test]
+fn returns_multiple_fields() {
+    assert_eq!(
+        expect_single!(values().into_iter().skip(1), Enum::Foo, (foo, bar)),
+        (4, false)
+    );
+}
+
+#[test]
+fn returns_the_element_without_fields() {
+    let values = values();
+    assert_eq!(expect_single!(&values, Enum::Pair), &Enum::Pair(2, 3));
+}
+
+#[test]
+fn panics_for_multiple_matches() {
+    assert_panics_with("expect_single!: expected exactly one element constructed with Enum::Foo, got 2: [Foo { foo: 1, bar: true }, Foo { foo: 4, bar: false }]", || {
+        expect_single!(values(), Enum::Foo, foo);
+    });
+}
+
+#[test]
+#[should_panic(
+    expected = "expect_single!: expected exactly one element constructed with Enum::Pair, got none"
+)]
+fn panics_without_matches() {
+    expect_single!(values().into_iter().take(1), Enum::Pair, 0);
+}
@@ -0,0 +1,48 @@
+use unsafe_get::extract_all;
+
+#[derive(Debug)]
+enum Enum {
+    Foo { foo: i32, bar: bool },
+    Pair(i32, i32),
+}
+
+fn values() -> Vec<Enum> {
+    vec![
+        Enum::Foo { foo: 1, bar: true },
+        Enum::Pair(2, 3),
+        Enum::Foo { foo: 4, bar: false },
+    ]
+}
+
+#[test]
+fn collects_fields_of_matching_elements() {
+    assert_eq!(extract_all!(values(), Enum::Foo, foo), vec![1, 4]);
+    assert_eq!(extract_all!(values(), Enum::Pair, 1), vec![3]);
+    assert_eq!(extract_all!(values(), Enum::Pair, 0), vec![2]);
+}
+
+#[test]
+fn works_with_references() {
+    let values = values();
+    assert_eq!(extract_all!(&values, Enum::Foo, bar), vec![&true, &false]);
+    assert_eq!(
+        extract_all!(values.iter().skip(1), Enum::Foo, foo),
+        vec![&4]
+    );
+}
+
+#[test]
+fn collects_multiple_fields() {
+    assert_eq!(
+        extract_all!(values(), Enum::Foo, (foo, bar)),
+        vec![(1, true), (4, false)]
+    );
+}
+
+#[test]
+fn returns_an_empty_vec_without_matches() {
+    assert_eq!(
+        extract_all!(values().into_iter().take(0), Enum::Foo, foo),
+        Vec::<i32>::new()
+    );
+}
@@ -1,3 +1,7 @@
+mod common;
+
+use common::assert_panics_with;
+use std::panic::catch_unwind;
 use unsafe_get::get;
 
 #[derive(Debug)]
@@ -12,12 +16,594 @@ fn returns_enum_fields() {
 }
 
 #[test]
-#[should_panic(expected = "get!: expected enum constructor: Enum::Foo, got Bar { bar: true }")]
 fn panics_in_case_of_getting_passed_in_the_wrong_enum_constructor() {
-    assert_eq!(get!(Enum::Bar { bar: true }, Enum::Foo, foo), 42);
+    assert_panics_with(
+        "get!: expected enum constructor: Enum::Foo, got Bar { bar: true }",
+        || {
+            assert_eq!(get!(Enum::Bar { bar: true }, Enum::Foo, foo), 42);
+        },
+    );
+}
+
+#[test]
+fn includes_the_matched_expression_in_panic_messages() {
+    assert_panics_with(
+        "got Bar { bar: false } while matching `parse(\"false\")`",
+        || {
+            fn parse(input: &str) -> Enum {
+                Enum::Bar {
+                    bar: input == "true",
+                }
+            }
+            get!(parse("false"), Enum::Foo, foo);
+        },
+    );
+}
+
+#[test]
+fn includes_the_call_site_in_panic_messages() {
+    let line = line!() + 1;
+    let payload = catch_unwind(|| get!(Enum::Bar { bar: true }, Enum::Foo)).unwrap_err();
+    let message = payload.downcast_ref::<String>().unwrap();
+    assert!(
+        message.starts_with(&format!("{}:{}:", file!(), line)),
+        "{}",
+        message
+    );
 }
 
 #[test]
+#[allow(clippy::bool_assert_comparison)]
 fn works_for_different_types() {
     assert_eq!(get!(Enum::Bar { bar: true }, Enum::Bar, bar), true);
 }
+
+#[derive(Debug)]
+enum TupleEnum {
+    Pair(i32, String),
+    Single(bool),
+}
+
+#[test]
+fn returns_tuple_variant_fields_by_position() {
+    let value = TupleEnum::Pair(42, "foo".to_string());
+    assert_eq!(get!(value, TupleEnum::Pair, 1), "foo");
+    assert!(get!(TupleEnum::Single(true), TupleEnum::Single, 0));
+}
+
+#[test]
+fn panics_for_tuple_variants_with_the_wrong_constructor() {
+    assert_panics_with(
+        "get!: expected enum constructor: TupleEnum::Pair, got Single(true)",
+        || {
+            get!(TupleEnum::Single(true), TupleEnum::Pair, 0);
+        },
+    );
+}
+
+#[derive(Debug)]
+enum MultiEnum {
+    Baz { a: i32, b: String, c: bool },
+    Qux,
+}
+
+#[test]
+fn returns_multiple_fields_as_a_tuple() {
+    let value = MultiEnum::Baz {
+        a: 42,
+        b: "foo".to_string(),
+        c: true,
+    };
+    assert_eq!(
+        get!(value, MultiEnum::Baz, (a, b, c)),
+        (42, "foo".to_string(), true)
+    );
+}
+
+#[test]
+#[should_panic(expected = "get!: expected enum constructor: MultiEnum::Baz, got Qux")]
+fn panics_when_extracting_multiple_fields_from_the_wrong_constructor() {
+    get!(MultiEnum::Qux, MultiEnum::Baz, (a, b));
+}
+
+#[derive(Debug)]
+enum Outer {
+    Wrapped { inner: Inner },
+    Empty,
+}
+
+#[derive(Debug)]
+enum Inner {
+    Leaf { x: i32 },
+    Node,
+}
+
+#[test]
+fn walks_through_nested_enums() {
+    let value = Outer::Wrapped {
+        inner: Inner::Leaf { x: 42 },
+    };
+    assert_eq!(get!(value, Outer::Wrapped, inner, Inner::Leaf, x), 42);
+}
+
+#[test]
+#[should_panic(expected = "get!: expected enum constructor: Outer::Wrapped, got Empty")]
+fn panics_when_the_outer_constructor_of_a_chain_mismatches() {
+    get!(Outer::Empty, Outer::Wrapped, inner, Inner::Leaf, x);
+}
+
+#[test]
+#[should_panic(
+    expected = "get!: expected enum constructor: Inner::Leaf, got Node while matching `value` (nested in Outer::Wrapped.inner)"
+)]
+fn reports_the_nesting_level_when_an_inner_constructor_mismatches() {
+    let value = Outer::Wrapped { inner: Inner::Node };
+    get!(value, Outer::Wrapped, inner, Inner::Leaf, x);
+}
+
+#[test]
+fn appends_custom_messages_to_the_panic_message() {
+    assert_panics_with("get!: expected enum constructor: Enum::Foo, got Bar { bar: true } while matching `Enum::Bar { bar: true }`: while checking request 3", || {
+        let id = 3;
+        get!(
+            Enum::Bar { bar: true },
+            Enum::Foo,
+            foo,
+            "while checking request {}",
+            id
+        );
+    });
+}
+
+#[test]
+#[should_panic(
+    expected = "get!: expected enum constructor: Inner::Leaf, got Node while matching `value` (nested in Outer::Wrapped.inner): in case nested"
+)]
+fn appends_custom_messages_to_mismatches_in_chains() {
+    let value = Outer::Wrapped { inner: Inner::Node };
+    get!(
+        value,
+        Outer::Wrapped,
+        inner,
+        Inner::Leaf,
+        x,
+        "in case {}",
+        "nested"
+    );
+}
+
+#[test]
+fn returns_references_to_fields_when_given_a_reference() {
+    let value = MultiEnum::Baz {
+        a: 42,
+        b: "foo".to_string(),
+        c: true,
+    };
+    let b: &String = get!(&value, MultiEnum::Baz, b);
+    assert_eq!(b, "foo");
+    assert_eq!(get!(&value, MultiEnum::Baz, (a, c)), (&42, &true));
+}
+
+#[test]
+fn returns_references_through_chains_when_given_a_reference() {
+    let value = Outer::Wrapped {
+        inner: Inner::Leaf { x: 42 },
+    };
+    let x: &i32 = get!(&value, Outer::Wrapped, inner, Inner::Leaf, x);
+    assert_eq!(x, &42);
+    get!(value, Outer::Wrapped, inner);
+}
+
+#[test]
+fn panics_for_references_to_the_wrong_constructor() {
+    assert_panics_with(
+        "get!: expected enum constructor: Enum::Foo, got Bar { bar: true }",
+        || {
+            get!(&Enum::Bar { bar: true }, Enum::Foo, foo);
+        },
+    );
+}
+
+enum NoDebug {
+    Foo { foo: i32 },
+    Bar,
+}
+
+#[test]
+fn works_for_enums_that_dont_implement_debug() {
+    assert_eq!(get!(NoDebug::Foo { foo: 42 }, NoDebug::Foo, foo), 42);
+}
+
+#[test]
+#[should_panic(
+    expected = "get!: expected enum constructor: NoDebug::Foo, got a value of type get::NoDebug (which doesn't implement Debug)"
+)]
+fn panics_with_the_type_name_for_enums_that_dont_implement_debug() {
+    get!(NoDebug::Bar, NoDebug::Foo, foo);
+}
+
+#[derive(Debug)]
+enum Expr {
+    Binary { lhs: Box<Expr>, rhs: Box<Expr> },
+    Literal { value: i32 },
+}
+
+fn binary(lhs: i32, rhs: i32) -> Expr {
+    Expr::Binary {
+        lhs: Box::new(Expr::Literal { value: lhs }),
+        rhs: Box::new(Expr::Literal { value: rhs }),
+    }
+}
+
+#[test]
+fn dereferences_boxes() {
+    let value = Box::new(binary(1, 2));
+    assert_eq!(get!(value, Expr::Binary, rhs, Expr::Literal, value), 2);
+}
+
+#[test]
+fn dereferences_references_to_boxes() {
+    let value = Box::new(binary(1, 2));
+    assert_eq!(get!(&value, Expr::Binary, lhs, Expr::Literal, value), &1);
+}
+
+#[test]
+fn dereferences_references_to_rcs_and_arcs() {
+    let value = std::rc::Rc::new(Expr::Literal { value: 42 });
+    assert_eq!(get!(&value, Expr::Literal, value), &42);
+    let value = std::sync::Arc::new(Expr::Literal { value: 42 });
+    assert_eq!(get!(&value, Expr::Literal, value), &42);
+}
+
+#[test]
+fn panics_with_the_dereferenced_value() {
+    assert_panics_with("get!: expected enum constructor: Expr::Binary, got Literal { value: 1 } while matching `value` (nested in Expr::Binary.lhs)", || {
+        let value = Box::new(binary(1, 2));
+        get!(value, Expr::Binary, lhs, Expr::Binary, lhs);
+    });
+}
+
+#[test]
+fn accepts_generic_arguments_in_constructor_paths() {
+    let value: Result<u8, String> = Ok(42);
+    assert_eq!(get!(value, Result::<u8, String>::Ok, 0), 42);
+}
+
+#[test]
+fn accepts_trailing_commas() {
+    assert_eq!(get!(Enum::Foo { foo: 42 }, Enum::Foo, foo,), 42);
+    let value = Outer::Wrapped {
+        inner: Inner::Leaf { x: 42 },
+    };
+    assert_eq!(get!(value, Outer::Wrapped, inner, Inner::Leaf, x,), 42);
+}
+
+#[derive(Debug)]
+struct Config {
+    timeout: u64,
+    inner: Inner,
+}
+
+#[derive(Debug)]
+enum Configured {
+    Foo { config: Config },
+    Bar,
+}
+
+fn configured() -> Configured {
+    Configured::Foo {
+        config: Config {
+            timeout: 42,
+            inner: Inner::Leaf { x: 23 },
+        },
+    }
+}
+
+#[test]
+fn projects_into_struct_fields() {
+    assert_eq!(get!(configured(), Configured::Foo, config.timeout), 42);
+}
+
+#[test]
+fn borrows_projected_struct_fields_when_given_a_reference() {
+    let value = configured();
+    let timeout: &u64 = get!(&value, Configured::Foo, config.timeout);
+    assert_eq!(timeout, &42);
+}
+
+#[test]
+fn continues_chains_after_projections() {
+    assert_eq!(
+        get!(configured(), Configured::Foo, config.inner, Inner::Leaf, x),
+        23
+    );
+}
+
+#[test]
+#[should_panic(
+    expected = "get!: expected enum constructor: Inner::Leaf, got Node while matching `value` (nested in Configured::Foo.config.inner)"
+)]
+fn reports_projections_in_nested_mismatches() {
+    let value = Configured::Foo {
+        config: Config {
+            timeout: 42,
+            inner: Inner::Node,
+        },
+    };
+    get!(value, Configured::Foo, config.inner, Inner::Leaf, x);
+}
+
+#[test]
+#[should_panic(expected = "get!: expected enum constructor: Configured::Foo, got Bar")]
+fn panics_before_projecting() {
+    get!(Configured::Bar, Configured::Foo, config.timeout);
+}
+
+#[test]
+fn passes_the_named_fields_to_closures() {
+    let value = MultiEnum::Baz {
+        a: 42,
+        b: "foo".to_string(),
+        c: true,
+    };
+    assert_eq!(
+        get!(&value, MultiEnum::Baz, |a, b| format!("{} {}", a, b)),
+        "42 foo"
+    );
+    assert!(get!(value, MultiEnum::Baz, |c| c));
+}
+
+#[test]
+#[should_panic(
+    expected = "get!: expected enum constructor: MultiEnum::Baz, got Qux while matching `MultiEnum::Qux`: closure"
+)]
+fn panics_before_calling_closures() {
+    get!(MultiEnum::Qux, MultiEnum::Baz, |a| a + 1, "closure");
+}
+
+#[test]
+fn returns_fields_when_guards_hold() {
+    assert_eq!(get!(Enum::Foo { foo: 42 }, Enum::Foo, foo, if foo > 0), 42);
+}
+
+#[test]
+fn panics_when_guards_dont_hold() {
+    assert_panics_with("get!: guard `foo > 0` failed for Foo { foo: -1 }", || {
+        get!(Enum::Foo { foo: -1 }, Enum::Foo, foo, if foo > 0);
+    });
+}
+
+#[test]
+fn panics_with_the_usual_message_when_guarded_constructors_mismatch() {
+    assert_panics_with(
+        "get!: expected enum constructor: Enum::Foo, got Bar { bar: true }",
+        || {
+            get!(Enum::Bar { bar: true }, Enum::Foo, foo, if foo > 0);
+        },
+    );
+}
+
+#[test]
+fn supports_guards_on_multiple_fields() {
+    let value = MultiEnum::Baz {
+        a: 42,
+        b: "foo".to_string(),
+        c: true,
+    };
+    assert_eq!(
+        get!(&value, MultiEnum::Baz, (a, c), if *c && *a > 0),
+        (&42, &true)
+    );
+}
+
+#[test]
+fn reports_context_and_messages_for_failed_guards() {
+    assert_panics_with("get!: guard `x < 0` failed for Leaf { x: 42 } while matching `value` (nested in Outer::Wrapped.inner): in case 3", || {
+        let value = Outer::Wrapped {
+            inner: Inner::Leaf { x: 42 },
+        };
+        get!(value, Outer::Wrapped, inner, Inner::Leaf, x, if x < 0, "in case {}", 3);
+    });
+}
+
+#[test]
+fn checks_unit_variants() {
+    get!(MultiEnum::Qux, MultiEnum::Qux);
+    get!(&MultiEnum::Qux, MultiEnum::Qux,);
+}
+
+#[test]
+fn panics_when_only_checking_the_wrong_constructor() {
+    assert_panics_with(
+        "get!: expected enum constructor: Enum::Bar, got Foo { foo: 42 }",
+        || {
+            get!(Enum::Foo { foo: 42 }, Enum::Bar);
+        },
+    );
+}
+
+#[test]
+fn checks_unit_variants_at_the_end_of_chains() {
+    assert_panics_with("get!: expected enum constructor: Inner::Node, got Leaf { x: 42 } while matching `value` (nested in Outer::Wrapped.inner)", || {
+        get!(
+            Outer::Wrapped { inner: Inner::Node },
+            Outer::Wrapped,
+            inner,
+            Inner::Node
+        );
+        let value = Outer::Wrapped {
+            inner: Inner::Leaf { x: 42 },
+        };
+        get!(value, Outer::Wrapped, inner, Inner::Node);
+    });
+}
+
+#[derive(Debug)]
+enum Pinned {
+    Foo { a: i32, b: i32, c: &'static str },
+    Bar,
+}
+
+#[test]
+fn checks_literal_values_of_sibling_fields() {
+    let value = Pinned::Foo {
+        a: 1,
+        b: 2,
+        c: "foo",
+    };
+    assert_eq!(get!(&value, Pinned::Foo, b, { a: 1, c: "foo" }), &2);
+    assert_eq!(get!(value, Pinned::Foo, (a, b), { c: "foo" }), (1, 2));
+}
+
+#[test]
+#[should_panic(
+    expected = "get!: expected field `a` of Pinned::Foo to match `1`, got 3, expected field `c` of Pinned::Foo to match `\"foo\"`, got \"bar\""
+)]
+fn names_all_sibling_fields_that_dont_match() {
+    let value = Pinned::Foo {
+        a: 3,
+        b: 2,
+        c: "bar",
+    };
+    get!(value, Pinned::Foo, b, { a: 1, c: "foo" });
+}
+
+#[test]
+#[should_panic(expected = "get!: expected enum constructor: Pinned::Foo, got Bar")]
+fn checks_the_constructor_before_sibling_fields() {
+    get!(Pinned::Bar, Pinned::Foo, b, { a: 1 });
+}
+
+#[test]
+fn checks_sibling_fields_of_tuple_variants() {
+    let pair = TupleEnum::Pair(42, "foo".to_string());
+    assert_eq!(get!(pair, TupleEnum::Pair, 1, { 0: 42 }), "foo");
+}
+
+#[test]
+fn unwraps_options_and_results() {
+    let value: Result<Option<Enum>, String> = Ok(Some(Enum::Foo { foo: 42 }));
+    assert_eq!(get!(unwrap & value, Enum::Foo, foo), &42);
+    assert_eq!(get!(unwrap value, Enum::Foo, foo), 42);
+    assert_eq!(get!(unwrap Enum::Foo { foo: 42 }, Enum::Foo, foo), 42);
+}
+
+#[test]
+#[should_panic(
+    expected = "get!: expected Ok(_), got Err(\"boom\") while matching `value`: in case 3"
+)]
+fn panics_when_unwrapping_errors() {
+    let value: Result<Option<Enum>, &str> = Err("boom");
+    get!(unwrap value, Enum::Foo, foo, "in case {}", 3);
+}
+
+#[test]
+#[should_panic(expected = "get!: expected Some(_), got None")]
+fn panics_when_unwrapping_nones() {
+    let value: Result<Option<Enum>, String> = Ok(None);
+    get!(unwrap value, Enum::Foo, foo);
+}
+
+#[test]
+fn panics_when_unwrapped_values_have_the_wrong_constructor() {
+    assert_panics_with(
+        "get!: expected enum constructor: Enum::Foo, got Bar { bar: true }",
+        || {
+            get!(unwrap Some(Enum::Bar { bar: true }), Enum::Foo, foo);
+        },
+    );
+}
+
+#[derive(Debug)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[test]
+fn works_for_structs() {
+    let point = Point { x: 1, y: 2 };
+    assert_eq!(get!(&point, Point, y, { x: 1 }), &2);
+    assert_eq!(get!(point, Point, (x, y)), (1, 2));
+}
+
+#[test]
+#[should_panic(expected = "get!: expected field `x` of Point to match `0`, got 1")]
+fn checks_pinned_fields_of_structs() {
+    get!(Point { x: 1, y: 2 }, Point, y, { x: 0 });
+}
+
+#[test]
+fn checks_ranges_of_sibling_fields() {
+    let value = Pinned::Foo {
+        a: 204,
+        b: 2,
+        c: "foo",
+    };
+    assert_eq!(get!(value, Pinned::Foo, b, { a: 200..=299 }), 2);
+}
+
+#[test]
+#[should_panic(expected = "get!: expected field `a` of Pinned::Foo to match `200..=299`, got 404")]
+fn reports_ranges_that_sibling_fields_dont_match() {
+    let value = Pinned::Foo {
+        a: 404,
+        b: 2,
+        c: "foo",
+    };
+    get!(value, Pinned::Foo, b, { a: 200..=299 });
+}
+
+#[derive(Debug, Clone)]
+enum Event {
+    Text { body: String },
+    Closed,
+}
+
+#[test]
+fn dereferences_references_to_cows() {
+    let event = Event::Text {
+        body: "foo".to_string(),
+    };
+    let borrowed: std::borrow::Cow<Event> = std::borrow::Cow::Borrowed(&event);
+    assert_eq!(get!(&borrowed, Event::Text, body), "foo");
+    let owned: std::borrow::Cow<Event> = std::borrow::Cow::Owned(Event::Closed);
+    get!(&owned, Event::Closed);
+}
+
+mod const_contexts {
+    use unsafe_get::get;
+
+    #[allow(dead_code)]
+    #[derive(Debug)]
+    enum Limit {
+        Fixed { min: u32, max: u32 },
+        Pair(u32, u32),
+        Unlimited,
+    }
+
+    const LIMIT: Limit = Limit::Fixed { min: 1, max: 10 };
+    const MAX: u32 = get!(const LIMIT, Limit::Fixed, max);
+    const BOUNDS: (u32, u32) = get!(const LIMIT, Limit::Fixed, (min, max));
+    const SECOND: u32 = get!(const Limit::Pair(1, 2), Limit::Pair, 1);
+    const UNIT: () = get!(const Limit::Unlimited, Limit::Unlimited);
+
+    const fn max(limit: &Limit) -> u32 {
+        *get!(const limit, Limit::Fixed, max)
+    }
+
+    #[test]
+    fn works_in_constants() {
+        assert_eq!(MAX, 10);
+        assert_eq!(BOUNDS, (1, 10));
+        assert_eq!(SECOND, 2);
+        let () = UNIT;
+        assert_eq!(max(&LIMIT), 10);
+    }
+
+    #[test]
+    #[should_panic(expected = "get!: expected enum constructor: Limit::Fixed")]
+    fn panics_at_runtime_outside_of_constants() {
+        max(&Limit::Unlimited);
+    }
+}
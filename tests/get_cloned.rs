@@ -0,0 +1,42 @@
+mod common;
+
+use common::assert_panics_with;
+use unsafe_get::get_cloned;
+
+#[derive(Debug)]
+enum Enum {
+    Foo { foo: String },
+    Bar { bar: bool },
+}
+
+#[test]
+fn returns_clones_of_enum_fields() {
+    let value = Enum::Foo {
+        foo: "foo".to_string(),
+    };
+    let foo: String = get_cloned!(value, Enum::Foo, foo);
+    assert_eq!(foo, "foo");
+    assert_eq!(get_cloned!(value, Enum::Foo, foo), "foo");
+}
+
+#[test]
+fn works_for_copy_fields() {
+    assert!(get_cloned!(Enum::Bar { bar: true }, Enum::Bar, bar));
+}
+
+#[test]
+fn panics_in_case_of_getting_passed_in_the_wrong_enum_constructor() {
+    assert_panics_with(
+        "get!: expected enum constructor: Enum::Foo, got Bar { bar: true }",
+        || {
+            let _ = get_cloned!(Enum::Bar { bar: true }, Enum::Foo, foo);
+        },
+    );
+}
+
+#[test]
+#[should_panic(expected = "while matching `values[0]`")]
+fn names_the_given_expression_in_panic_messages() {
+    let values = [Enum::Bar { bar: true }];
+    let _ = get_cloned!(values[0], Enum::Foo, foo);
+}
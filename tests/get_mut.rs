@@ -0,0 +1,50 @@
+mod common;
+
+use common::assert_panics_with;
+use unsafe_get::get_mut;
+
+#[derive(Debug, PartialEq)]
+enum Enum {
+    Foo { foo: i32 },
+    Bar { bar: Box<Enum> },
+}
+
+#[test]
+fn returns_mutable_references_to_enum_fields() {
+    let mut value = Enum::Foo { foo: 1 };
+    let foo: &mut i32 = get_mut!(&mut value, Enum::Foo, foo);
+    *foo += 41;
+    assert_eq!(value, Enum::Foo { foo: 42 });
+}
+
+#[test]
+fn returns_mutable_references_through_chains() {
+    let mut value = Enum::Bar {
+        bar: Box::new(Enum::Foo { foo: 1 }),
+    };
+    *get_mut!(&mut value, Enum::Bar, bar, Enum::Foo, foo) = 42;
+    assert_eq!(
+        value,
+        Enum::Bar {
+            bar: Box::new(Enum::Foo { foo: 42 })
+        }
+    );
+}
+
+#[test]
+fn panics_in_case_of_getting_passed_in_the_wrong_enum_constructor() {
+    assert_panics_with(
+        "get!: expected enum constructor: Enum::Bar, got Foo { foo: 1 }",
+        || {
+            let mut value = Enum::Foo { foo: 1 };
+            get_mut!(&mut value, Enum::Bar, bar);
+        },
+    );
+}
+
+#[test]
+#[should_panic(expected = "while matching `&mut value`")]
+fn names_the_given_expression_in_panic_messages() {
+    let mut value = Enum::Foo { foo: 1 };
+    get_mut!(&mut value, Enum::Bar, bar);
+}
@@ -0,0 +1,57 @@
+use unsafe_get::get_or;
+
+#[derive(Debug)]
+enum Enum {
+    Foo { foo: i32 },
+    Bar { bar: bool },
+}
+
+#[test]
+fn returns_enum_fields() {
+    assert_eq!(get_or!(Enum::Foo { foo: 42 }, Enum::Foo, foo, 0), 42);
+}
+
+#[test]
+fn returns_the_fallback_for_other_enum_constructors() {
+    assert_eq!(get_or!(Enum::Bar { bar: true }, Enum::Foo, foo, 0), 0);
+}
+
+#[test]
+fn only_evaluates_the_fallback_on_mismatch() {
+    assert!(get_or!(
+        Enum::Bar { bar: true },
+        Enum::Bar,
+        bar,
+        panic!("fallback evaluated")
+    ));
+}
+
+#[test]
+fn accepts_trailing_commas() {
+    assert_eq!(get_or!(Enum::Foo { foo: 42 }, Enum::Foo, foo, 0,), 42);
+}
+
+#[test]
+fn allows_diverging_fallbacks() {
+    let values = vec![
+        Enum::Foo { foo: 1 },
+        Enum::Bar { bar: true },
+        Enum::Foo { foo: 2 },
+    ];
+    let mut foos = vec![];
+    for value in values {
+        foos.push(get_or!(value, Enum::Foo, foo, continue));
+    }
+    assert_eq!(foos, vec![1, 2]);
+    let bar_or_none = |value: Enum| Some(get_or!(value, Enum::Bar, bar, return None));
+    assert_eq!(bar_or_none(Enum::Foo { foo: 1 }), None);
+}
+
+#[test]
+fn looks_through_boxes() {
+    let value = Box::new(Enum::Foo { foo: 42 });
+    assert_eq!(get_or!(&value, Enum::Foo, foo, &0), &42);
+    assert!(!get_or!(value, Enum::Bar, bar, false));
+    let shared = std::rc::Rc::new(Enum::Bar { bar: true });
+    assert_eq!(get_or!(&shared, Enum::Bar, bar, &false), &true);
+}
@@ -0,0 +1,51 @@
+mod common;
+
+use common::squash;
+use unsafe_get::{get_result, UnexpectedVariant};
+
+#[derive(Debug)]
+enum Enum {
+    Foo { foo: i32 },
+    Bar { bar: bool },
+}
+
+#[test]
+fn returns_enum_fields() {
+    assert_eq!(get_result!(Enum::Foo { foo: 42 }, Enum::Foo, foo), Ok(42));
+}
+
+#[test]
+fn returns_errors_for_other_enum_constructors() {
+    let mut error = get_result!(Enum::Bar { bar: true }, Enum::Foo, foo).unwrap_err();
+    error.value = squash(&error.value);
+    assert_eq!(
+        error,
+        UnexpectedVariant {
+            expected: "Enum::Foo",
+            actual: "Bar".to_string(),
+            value: squash("Bar { bar: true }"),
+        }
+    );
+}
+
+#[test]
+fn composes_with_the_question_mark_operator() {
+    fn helper(value: Enum) -> Result<bool, Box<dyn std::error::Error>> {
+        Ok(!get_result!(value, Enum::Bar, bar)?)
+    }
+    assert!(!helper(Enum::Bar { bar: true }).unwrap());
+    assert_eq!(
+        squash(&helper(Enum::Foo { foo: 42 }).unwrap_err().to_string()),
+        squash("expected enum constructor: Enum::Bar, got Foo { foo: 42 }")
+    );
+}
+
+#[test]
+fn looks_through_boxes() {
+    let value = Box::new(Enum::Foo { foo: 42 });
+    assert_eq!(get_result!(&value, Enum::Foo, foo), Ok(&42));
+    assert_eq!(
+        get_result!(value, Enum::Bar, bar).unwrap_err().actual,
+        "Foo".to_string()
+    );
+}
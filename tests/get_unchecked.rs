@@ -0,0 +1,35 @@
+use unsafe_get::get_unchecked;
+
+#[allow(dead_code)]
+#[derive(Debug)]
+enum Enum {
+    Foo { foo: i32, bar: bool },
+    Pair(i32, i32),
+}
+
+#[test]
+fn returns_enum_fields() {
+    let value = Enum::Foo { foo: 42, bar: true };
+    assert_eq!(unsafe { get_unchecked!(&value, Enum::Foo, foo) }, &42);
+    assert!(unsafe { get_unchecked!(value, Enum::Foo, bar) });
+    assert_eq!(
+        unsafe { get_unchecked!(Enum::Pair(1, 2), Enum::Pair, 1) },
+        2
+    );
+}
+
+#[test]
+fn returns_multiple_fields() {
+    let value = Enum::Foo { foo: 42, bar: true };
+    assert_eq!(
+        unsafe { get_unchecked!(value, Enum::Foo, (foo, bar)) },
+        (42, true)
+    );
+}
+
+#[test]
+fn looks_through_boxes() {
+    let value = Box::new(Enum::Foo { foo: 42, bar: true });
+    assert_eq!(unsafe { get_unchecked!(&value, Enum::Foo, foo) }, &42);
+    assert!(unsafe { get_unchecked!(value, Enum::Foo, bar) });
+}
@@ -0,0 +1,28 @@
+use unsafe_get::is_variant;
+
+#[allow(dead_code)]
+#[derive(Debug)]
+enum Enum {
+    Foo { foo: i32 },
+    Bar(i32),
+    Baz,
+}
+
+fn count(values: &[Enum], predicate: impl Fn(&Enum) -> bool) -> usize {
+    values.iter().filter(|value| predicate(value)).count()
+}
+
+#[test]
+fn returns_a_predicate_for_the_constructor() {
+    let values = [Enum::Foo { foo: 1 }, Enum::Bar(2), Enum::Baz, Enum::Bar(3)];
+    assert_eq!(count(&values, is_variant!(Enum::Foo)), 1);
+    assert_eq!(count(&values, is_variant!(Enum::Bar)), 2);
+    assert_eq!(count(&values, is_variant!(Enum::Baz)), 1);
+}
+
+#[test]
+fn can_be_stored_and_called() {
+    let is_bar = is_variant!(Enum::Bar);
+    assert!(is_bar(&Enum::Bar(1)));
+    assert!(!is_bar(&Enum::Baz));
+}
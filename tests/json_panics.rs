@@ -0,0 +1,99 @@
+use std::env;
+use std::fmt;
+use std::panic::catch_unwind;
+use std::sync::mpsc::channel;
+use std::time::Duration;
+use unsafe_get::{assert_variant, get, recv_variant};
+
+#[allow(dead_code)]
+enum Enum {
+    Foo { foo: i32 },
+    Bar { bar: String },
+}
+
+fn panic_message(value: Enum) -> String {
+    let payload = catch_unwind(|| get!(value, Enum::Foo, foo)).unwrap_err();
+    payload.downcast_ref::<String>().unwrap().clone()
+}
+
+// `Debug` is implemented by hand, so that the values in the expected JSON
+// don't depend on the `pretty-debug` feature.
+impl fmt::Debug for Enum {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Enum::Foo { foo: value } => write!(formatter, "Foo {{ foo: {:?} }}", value),
+            Enum::Bar { bar: value } => write!(formatter, "Bar {{ bar: {:?} }}", value),
+        }
+    }
+}
+
+// The tests share the process environment, so they're run in one test.
+#[test]
+fn formats_panic_messages_as_json_when_enabled() {
+    // The `color` feature appends a colored diff to the panic messages.
+    env::set_var("NO_COLOR", "1");
+    env::set_var("UNSAFE_GET_PANIC_FORMAT", "json");
+    assert_eq!(
+        panic_message(Enum::Bar {
+            bar: "\"quoted\"\n".to_string()
+        }),
+        concat!(
+            r#"{"location":"tests/json_panics.rs:15:35","#,
+            r#""expression":"value","#,
+            r#""expected":"Enum::Foo","#,
+            r#""actual":"Bar","#,
+            r#""value":"Bar { bar: \"\\\"quoted\\\"\\n\" }","#,
+            r#""message":"tests/json_panics.rs:15:35: get!: expected enum constructor: Enum::Foo, got Bar { bar: \"\\\"quoted\\\"\\n\" } while matching `value`"}"#
+        )
+    );
+    let payload = catch_unwind(|| get!(unwrap None::<Enum>, Enum::Foo, foo)).unwrap_err();
+    assert_eq!(
+        payload.downcast_ref::<String>().unwrap(),
+        concat!(
+            r#"{"location":"tests/json_panics.rs:49:35","#,
+            r#""expression":"None::<Enum>","#,
+            r#""expected":"Some(_)","#,
+            r#""actual":"None","#,
+            r#""value":"None","#,
+            r#""message":"tests/json_panics.rs:49:35: get!: expected Some(_), got None while matching `None::<Enum>`"}"#
+        )
+    );
+
+    let line = line!() + 1;
+    let payload = catch_unwind(|| assert_variant!(Enum::Foo { foo: 1 }, Enum::Bar)).unwrap_err();
+    let location = format!("tests/json_panics.rs:{}:35", line);
+    assert_eq!(
+        payload.downcast_ref::<String>().unwrap(),
+        &format!(
+            concat!(
+                r#"{{"location":"{}","#,
+                r#""expression":"Enum::Foo {{ foo: 1 }}","#,
+                r#""expected":"Enum::Bar","#,
+                r#""actual":"Foo","#,
+                r#""value":"Foo {{ foo: 1 }}","#,
+                r#""message":"{}: assert_variant!: expected enum constructor: Enum::Bar, got Foo {{ foo: 1 }}"}}"#
+            ),
+            location, location
+        )
+    );
+    let payload = catch_unwind(|| {
+        let (_sender, receiver) = channel::<Enum>();
+        recv_variant!(receiver, Duration::from_millis(1), Enum::Foo { .. });
+    })
+    .unwrap_err();
+    assert!(payload.downcast_ref::<String>().unwrap().ends_with(concat!(
+        r#""expression":"receiver","#,
+        r#""expected":"Enum::Foo { .. }","#,
+        r#""actual":null,"#,
+        r#""value":"","#,
+        r#""message":"tests/json_panics.rs:81:9: recv_variant!: expected pattern: Enum::Foo { .. }, but timed out after 1ms"}"#
+    )));
+
+    env::remove_var("UNSAFE_GET_PANIC_FORMAT");
+    assert_eq!(
+        panic_message(Enum::Bar {
+            bar: "bar".to_string()
+        }),
+        "tests/json_panics.rs:15:35: get!: expected enum constructor: Enum::Foo, got Bar { bar: \"bar\" } while matching `value`"
+    );
+}
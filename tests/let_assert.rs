@@ -0,0 +1,302 @@
+mod common;
+
+use common::assert_panics_with;
+use unsafe_get::let_assert;
+
+#[derive(Debug)]
+enum Enum {
+    Foo { foo: i32 },
+    Bar(Option<String>),
+    Baz { a: i32, b: String },
+}
+
+#[test]
+fn binds_variables_in_the_current_scope() {
+    let_assert!(Enum::Foo { foo } = Enum::Foo { foo: 42 });
+    assert_eq!(foo, 42);
+    let value = Enum::Bar(Some("bar".to_string()));
+    let_assert!(Enum::Bar(Some(bar)) = &value);
+    assert_eq!(bar, "bar");
+}
+
+#[test]
+fn panics_with_expression_value_and_pattern() {
+    assert_panics_with("let_assert!: value doesn't match pattern\n  expression: Enum::Bar(None)\n       value: Bar(None)\n     pattern: Enum::Foo { foo }", || {
+        let_assert!(Enum::Foo { foo } = Enum::Bar(None));
+        assert_eq!(foo, 42);
+    });
+}
+
+#[test]
+fn matches_nested_patterns() {
+    let result: Result<Option<Enum>, String> = Ok(Some(Enum::Foo { foo: 42 }));
+    let_assert!(Ok(Some(Enum::Foo { foo })) = result);
+    assert_eq!(foo, 42);
+}
+
+#[test]
+fn panics_with_the_whole_nested_pattern() {
+    assert_panics_with("let_assert!: fields of Ok don't match pattern\n  expression: result\n       value: Ok(None)\n     pattern: Ok(Some(Enum::Foo { foo }))", || {
+        let result: Result<Option<Enum>, String> = Ok(None);
+        let_assert!(Ok(Some(Enum::Foo { foo })) = result);
+        assert_eq!(foo, 42);
+    });
+}
+
+#[test]
+fn allows_mixing_renamed_and_shorthand_bindings() {
+    let_assert!(
+        Enum::Baz { a: x, b } = Enum::Baz {
+            a: 1,
+            b: "b".to_string()
+        }
+    );
+    assert_eq!((x, b.as_str()), (1, "b"));
+}
+
+#[test]
+fn allows_mut_bindings() {
+    let_assert!(Enum::Foo { mut foo } = Enum::Foo { foo: 1 });
+    foo += 1;
+    assert_eq!(foo, 2);
+}
+
+#[test]
+fn allows_ref_bindings() {
+    let mut value = Enum::Bar(Some("bar".to_string()));
+    {
+        let_assert!(Enum::Bar(Some(ref mut bar)) = value);
+        bar.push('!');
+    }
+    let_assert!(Enum::Bar(Some(ref bar)) = value);
+    assert_eq!(bar, "bar!");
+    assert!(matches!(value, Enum::Bar(Some(_))));
+}
+
+#[test]
+fn allows_at_bindings() {
+    let_assert!(Enum::Foo { foo: n @ 1..=50 } = Enum::Foo { foo: 42 });
+    assert_eq!(n, 42);
+    assert_panics_with(
+        "let_assert!: fields of Enum::Foo don't match pattern",
+        || {
+            let_assert!(Enum::Foo { foo: n @ 1..=10 } = Enum::Foo { foo: 42 });
+            assert_eq!(n, 42);
+        },
+    );
+}
+
+#[test]
+fn accepts_top_level_or_patterns() {
+    let result: Result<i32, i32> = Err(42);
+    let_assert!(Ok(number) | Err(number) = result);
+    assert_eq!(number, 42);
+    let_assert!(|Enum::Bar(None)| Enum::Foo { .. } = Enum::Bar(None));
+}
+
+#[test]
+fn panics_if_no_alternative_matches() {
+    assert_panics_with("let_assert!: value doesn't match pattern\n  expression: Enum::Bar(None)\n       value: Bar(None)\n     pattern: Enum::Baz { b, .. } | Enum::Bar(Some(b))", || {
+        let_assert!(Enum::Baz { b, .. } | Enum::Bar(Some(b)) = Enum::Bar(None));
+        assert_eq!(b, "b");
+    });
+}
+
+#[test]
+fn destructures_tuples() {
+    let result: Result<i32, String> = Ok(1);
+    let option = Some("b");
+    let_assert!((Ok(a), Some(b)) = (result, option));
+    assert_eq!((a, b), (1, "b"));
+    assert_panics_with("let_assert!: value doesn't match pattern\n  expression: (result, option)\n       value: (Ok(1), None)\n     pattern: (Ok(a), Some(b))", || {
+        let result: Result<i32, String> = Ok(1);
+        let option: Option<&str> = None;
+        let_assert!((Ok(a), Some(b)) = (result, option));
+        assert_eq!((a, b), (1, "b"));
+    });
+}
+
+#[test]
+fn matches_slices_and_arrays() {
+    let events = [1, 2, 3];
+    let_assert!([first, .., last] = &events[..]);
+    assert_eq!((first, last), (&1, &3));
+    let_assert!([_, middle, 3] = [1, 2, 3]);
+    assert_eq!(middle, 2);
+    assert_panics_with("let_assert!: value doesn't match pattern\n  expression: &events[..]\n       value: [1, 2, 3]\n     pattern: [_, _]", || {
+        let_assert!([_, _] = &events[..]);
+    });
+}
+
+#[test]
+fn runs_else_blocks_instead_of_panicking() {
+    let mut matched = Vec::new();
+    for value in [Enum::Foo { foo: 1 }, Enum::Bar(None), Enum::Foo { foo: 2 }] {
+        let_assert!(Enum::Foo { foo } = value, else { continue });
+        matched.push(foo);
+    }
+    assert_eq!(matched, vec![1, 2]);
+}
+
+#[test]
+fn binds_several_patterns() {
+    let first = Enum::Foo { foo: 1 };
+    let_assert!(
+        Enum::Foo { foo } = first,
+        Enum::Bar(Some(bar)) = Enum::Bar(Some("bar".to_string())),
+        Some(n) = Some(foo + 1), if n > 1,
+    );
+    assert_eq!((foo, bar.as_str(), n), (1, "bar", 2));
+    assert_panics_with("let_assert!: fields of Enum::Bar don't match pattern\n  expression: Enum::Bar(None)\n       value: Bar(None)\n     pattern: Enum::Bar(Some(bar))\nin case 3", || {
+        let_assert!(
+            Enum::Foo { foo } = first,
+            Enum::Bar(Some(bar)) = Enum::Bar(None),
+            "in case {}",
+            3
+        );
+        assert_eq!((foo, bar), (1, "bar".to_string()));
+    });
+}
+
+#[test]
+fn returns_the_body_in_expression_position() {
+    let value = Enum::Baz {
+        a: 1,
+        b: "b".to_string(),
+    };
+    let (a, b) = let_assert!(Enum::Baz { a, b } = value => (a, b));
+    assert_eq!((a, b.as_str()), (1, "b"));
+    assert_eq!(
+        i32::abs(let_assert!(Enum::Foo { foo } = Enum::Foo { foo: -1 }, if foo < 0 => foo)),
+        1
+    );
+    assert_panics_with(
+        "     pattern: Enum::Foo { foo }\nin case 3",
+        || let_assert!(Enum::Foo { foo } = Enum::Bar(None) => foo, "in case {}", 3),
+    );
+}
+
+#[test]
+fn binds_references_when_matching_references() {
+    let mut value = Enum::Foo { foo: 1 };
+    let_assert!(Enum::Foo { foo } = &mut value);
+    *foo += 1;
+    let_assert!(Enum::Foo { foo } = &value);
+    assert_eq!(foo, &2);
+    assert!(matches!(value, Enum::Foo { foo: 2 }));
+}
+
+#[test]
+fn matches_through_boxes() {
+    #[derive(Debug)]
+    enum Expr {
+        Negate { operand: Box<Expr> },
+        Literal(i32),
+    }
+    struct Node {
+        child: Box<Expr>,
+    }
+    let node = Node {
+        child: Box::new(Expr::Negate {
+            operand: Box::new(Expr::Literal(1)),
+        }),
+    };
+    let_assert!(Expr::Negate { .. } = &node.child);
+    let_assert!(Expr::Negate { operand } = node.child);
+    let_assert!(Expr::Literal(one) = *operand);
+    assert_eq!(one, 1);
+    assert_panics_with("let_assert!: value doesn't match pattern\n  expression: Box::new(Expr::Literal(3))\n       value: Literal(3)\n     pattern: Expr::Negate { .. }", || {
+        let_assert!(Expr::Negate { .. } = Box::new(Expr::Literal(3)));
+    });
+}
+
+#[test]
+fn allows_rest_patterns_in_tuple_variants() {
+    #[derive(Debug)]
+    enum Triple {
+        Triple(i32, i32, i32),
+    }
+    let_assert!(Triple::Triple(a, ..) = Triple::Triple(1, 2, 3));
+    let_assert!(Triple::Triple(.., c) = Triple::Triple(1, 2, 3));
+    let_assert!(Triple::Triple(_, b, ..) = Triple::Triple(1, 2, 3));
+    assert_eq!((a, b, c), (1, 2, 3));
+}
+
+#[test]
+fn checks_literal_sub_patterns() {
+    let_assert!(
+        Enum::Baz { a: 3, b } = Enum::Baz {
+            a: 3,
+            b: "b".to_string()
+        }
+    );
+    assert_eq!(b, "b");
+}
+
+#[test]
+fn panics_with_the_part_of_the_pattern_that_doesnt_match() {
+    assert_panics_with("let_assert!: fields of Enum::Baz don't match pattern\n  expression: value\n       value: Baz { a: 4, b: \"b\" }\n     pattern: Enum::Baz { a: 3, b }", || {
+        let value = Enum::Baz {
+            a: 4,
+            b: "b".to_string(),
+        };
+        let_assert!(Enum::Baz { a: 3, b } = value);
+        assert_eq!(b, "b");
+    });
+    assert_panics_with("let_assert!: value doesn't match pattern\n  expression: Enum::Bar(None)\n       value: Bar(None)\n     pattern: Enum::Baz { a: 3, b }", || {
+        let_assert!(Enum::Baz { a: 3, b } = Enum::Bar(None));
+        assert_eq!(b, "b");
+    });
+}
+
+#[test]
+fn binds_variables_if_the_guard_holds() {
+    let_assert!(Enum::Foo { foo } = Enum::Foo { foo: 42 }, if foo > 10);
+    assert_eq!(foo, 42);
+}
+
+#[test]
+fn panics_with_the_failed_guard() {
+    assert_panics_with("let_assert!: guard failed\n  expression: Enum::Foo { foo: 5 }\n       value: Foo { foo: 5 }\n     pattern: Enum::Foo { foo }\n       guard: foo > 10\nin case 3", || {
+        let_assert!(Enum::Foo { foo } = Enum::Foo { foo: 5 }, if foo > 10, "in case {}", 3);
+    });
+    assert_panics_with("let_assert!: value doesn't match pattern\n  expression: Enum::Bar(None)\n       value: Bar(None)\n     pattern: Enum::Foo { foo }\n", || {
+        let_assert!(Enum::Foo { foo } = Enum::Bar(None), if foo > 10);
+    });
+}
+
+#[test]
+#[should_panic(expected = "     pattern: Enum::Bar(Some(_))\nin case 3")]
+fn appends_custom_messages() {
+    let_assert!(Enum::Bar(Some(_)) = Enum::Bar(None), "in case {}", 3);
+}
+
+#[test]
+fn appends_custom_messages_for_variables() {
+    let case_name = "second";
+    assert_panics_with(
+        "     pattern: Enum::Foo { foo }\ncase second: wrong event",
+        || {
+            let value = Enum::Bar(None);
+            let_assert!(Enum::Foo { foo } = value, "case {}: wrong event", case_name);
+            assert_eq!(foo, 42);
+        },
+    );
+}
+
+#[cfg(feature = "proc-macro")]
+mod full_pattern_grammar {
+    use super::{assert_panics_with, Enum};
+    use unsafe_get::let_assert;
+
+    #[test]
+    fn panics_with_the_original_pattern_text() {
+        assert_panics_with("let_assert!: value doesn't match pattern\n  expression: Enum::Bar(None)\n       value: Bar(None)\n     pattern: Enum::Foo { foo: 1 } | Enum::Bar(Some(_))\nin case 3", || {
+            let_assert!(
+                Enum::Foo { foo: 1 } | Enum::Bar(Some(_)) = Enum::Bar(None),
+                "in case {}",
+                3
+            );
+        });
+    }
+}
@@ -0,0 +1,41 @@
+use unsafe_get::map_variant;
+
+#[derive(Debug, Clone, PartialEq)]
+enum Enum {
+    Foo { a: i32, b: String },
+    Bar,
+}
+
+fn base() -> Enum {
+    Enum::Foo {
+        a: 1,
+        b: "foo".to_string(),
+    }
+}
+
+#[test]
+fn modifies_the_named_fields() {
+    assert_eq!(
+        map_variant!(base(), Enum::Foo, |a| *a += 41),
+        Enum::Foo {
+            a: 42,
+            b: "foo".to_string()
+        }
+    );
+    assert_eq!(
+        map_variant!(base(), Enum::Foo, |a, b| {
+            *a = 2;
+            b.push_str("bar");
+        }),
+        Enum::Foo {
+            a: 2,
+            b: "foobar".to_string()
+        }
+    );
+}
+
+#[test]
+#[should_panic(expected = "get!: expected enum constructor: Enum::Foo, got Bar")]
+fn panics_in_case_of_getting_passed_in_the_wrong_enum_constructor() {
+    map_variant!(Enum::Bar, Enum::Foo, |a| *a += 1);
+}
@@ -0,0 +1,38 @@
+use unsafe_get::matches_get;
+
+#[derive(Debug)]
+enum Enum {
+    Foo { a: i32, b: i32 },
+    Bar,
+}
+
+#[test]
+fn returns_the_result_for_matching_values() {
+    assert_eq!(
+        matches_get!(Enum::Foo { a: 1, b: 2 }, Enum::Foo { a, b } => a + b),
+        Some(3)
+    );
+}
+
+#[test]
+fn returns_none_for_values_that_dont_match() {
+    assert_eq!(matches_get!(Enum::Bar, Enum::Foo { a, .. } => a), None);
+    assert_eq!(
+        matches_get!(Enum::Foo { a: 1, b: 2 }, Enum::Foo { a, .. } if a > 1 => a),
+        None
+    );
+}
+
+#[test]
+fn can_be_used_for_filtering() {
+    let values = [
+        Enum::Foo { a: 1, b: 2 },
+        Enum::Bar,
+        Enum::Foo { a: 3, b: 4 },
+    ];
+    let sums: Vec<i32> = values
+        .iter()
+        .filter_map(|value| matches_get!(value, Enum::Foo { a, b } => a + b))
+        .collect();
+    assert_eq!(sums, vec![3, 7]);
+}
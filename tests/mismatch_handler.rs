@@ -0,0 +1,76 @@
+use std::panic::catch_unwind;
+use unsafe_get::{
+    assert_variant, get, set_mismatch_handler, some, take_mismatch_handler, MismatchInfo,
+};
+
+#[derive(Debug)]
+enum Outer {
+    Wrapped { inner: Inner },
+}
+
+#[allow(dead_code)]
+#[derive(Debug)]
+enum Inner {
+    Leaf { x: i32 },
+    Node,
+}
+
+fn panic_message(value: Outer) -> String {
+    let payload = catch_unwind(|| get!(value, Outer::Wrapped, inner, Inner::Leaf, x)).unwrap_err();
+    payload.downcast_ref::<String>().unwrap().clone()
+}
+
+fn other_macro_panic_message() -> (String, String) {
+    let line = line!() + 1;
+    let payload = catch_unwind(|| assert_variant!(Inner::Node, Inner::Leaf)).unwrap_err();
+    let message = payload.downcast_ref::<String>().unwrap().clone();
+    (format!("{}:{}:", file!(), line), message)
+}
+
+fn describe(info: MismatchInfo) -> String {
+    format!(
+        "location: {}, expression: {}, expected: {}, actual: {:?}, value: {}, context: {:?}",
+        info.location, info.expression, info.expected, info.actual, info.value, info.context
+    )
+}
+
+// The handler is global, so it's tested in one test.
+#[test]
+fn formats_panic_messages_with_the_installed_handler() {
+    // The `color` feature appends a colored diff to the panic messages.
+    std::env::set_var("NO_COLOR", "1");
+    let value = || Outer::Wrapped { inner: Inner::Node };
+    let default = panic_message(value());
+    assert_eq!(
+        default,
+        "tests/mismatch_handler.rs:19:35: get!: expected enum constructor: Inner::Leaf, got Node while matching `value` (nested in Outer::Wrapped.inner)"
+    );
+
+    set_mismatch_handler(describe);
+    assert_eq!(
+        panic_message(value()),
+        "location: tests/mismatch_handler.rs:19:35, expression: value, expected: Inner::Leaf, actual: Some(\"Node\"), value: Node, context: [\"Outer::Wrapped.inner\"]"
+    );
+
+    let (location, message) = other_macro_panic_message();
+    assert!(message.starts_with(&format!("location: {}", location)));
+    assert!(message.ends_with(
+        ", expression: Inner::Node, expected: Inner::Leaf, actual: Some(\"Node\"), value: Node, context: []"
+    ));
+    let payload = catch_unwind(|| some!(None::<i32>)).unwrap_err();
+    assert!(payload.downcast_ref::<String>().unwrap().ends_with(
+        ", expression: None::<i32>, expected: Some(_), actual: Some(\"None\"), value: None, context: []"
+    ));
+
+    set_mismatch_handler(|info| format!("[custom] {}", info.message));
+    assert_eq!(panic_message(value()), format!("[custom] {}", default));
+    let (location, message) = other_macro_panic_message();
+    assert!(message.starts_with(&format!("[custom] {}", location)));
+    assert!(
+        message.ends_with(": assert_variant!: expected enum constructor: Inner::Leaf, got Node")
+    );
+
+    assert!(take_mismatch_handler().is_some());
+    assert!(take_mismatch_handler().is_none());
+    assert_eq!(panic_message(value()), default);
+}
@@ -0,0 +1,27 @@
+use unsafe_get::{err, ok};
+
+#[test]
+fn ok_returns_the_ok_value() {
+    let result: Result<i32, String> = Ok(42);
+    assert_eq!(ok!(result), 42);
+}
+
+#[test]
+#[should_panic(expected = "ok!: expected `result` to be Ok(_), got Err(\"boom\")")]
+fn ok_panics_with_the_error() {
+    let result: Result<i32, &str> = Err("boom");
+    ok!(result);
+}
+
+#[test]
+fn err_returns_the_error() {
+    let result: Result<i32, String> = Err("boom".to_string());
+    assert_eq!(err!(result), "boom");
+}
+
+#[test]
+#[should_panic(expected = "err!: expected `result` to be Err(_), got Ok(42): in case 3")]
+fn err_panics_with_the_ok_value() {
+    let result: Result<i32, &str> = Ok(42);
+    err!(result, "in case {}", 3);
+}
@@ -0,0 +1,42 @@
+use unsafe_get::partition_variants;
+
+#[derive(Debug, PartialEq)]
+enum Enum {
+    Foo { foo: i32 },
+    Pair(i32, i32),
+    Bar,
+}
+
+fn values() -> Vec<Enum> {
+    vec![
+        Enum::Foo { foo: 1 },
+        Enum::Pair(1, 2),
+        Enum::Bar,
+        Enum::Foo { foo: 2 },
+    ]
+}
+
+#[test]
+fn splits_off_one_constructor() {
+    let (foos, rest) = partition_variants!(values(), Enum::Foo);
+    assert_eq!(foos, vec![Enum::Foo { foo: 1 }, Enum::Foo { foo: 2 }]);
+    assert_eq!(rest, vec![Enum::Pair(1, 2), Enum::Bar]);
+}
+
+#[test]
+fn splits_off_multiple_constructors() {
+    let (bars, pairs, foos, rest) =
+        partition_variants!(values(), Enum::Bar, Enum::Pair, Enum::Foo,);
+    assert_eq!(bars, vec![Enum::Bar]);
+    assert_eq!(pairs, vec![Enum::Pair(1, 2)]);
+    assert_eq!(foos, vec![Enum::Foo { foo: 1 }, Enum::Foo { foo: 2 }]);
+    assert!(rest.is_empty());
+}
+
+#[test]
+fn works_with_references() {
+    let values = values();
+    let (pairs, rest) = partition_variants!(&values, Enum::Pair);
+    assert_eq!(pairs, vec![&Enum::Pair(1, 2)]);
+    assert_eq!(rest.len(), 3);
+}
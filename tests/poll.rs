@@ -0,0 +1,52 @@
+mod common;
+
+use common::assert_panics_with;
+use std::task::Poll;
+use unsafe_get::{expect_pending, expect_ready};
+
+#[test]
+fn expect_ready_returns_ready_values() {
+    assert_eq!(expect_ready!(Poll::Ready(42)), 42);
+    let poll = Poll::Ready("foo".to_string());
+    assert_eq!(expect_ready!(&poll), "foo");
+}
+
+#[test]
+#[should_panic(
+    expected = "expect_ready!: expected `Poll::<i32>::Pending` to be Ready(_), got Pending"
+)]
+fn expect_ready_panics_for_pending() {
+    expect_ready!(Poll::<i32>::Pending);
+}
+
+#[test]
+#[should_panic(expected = "expect_ready!: expected `poll` to be Ready(_), got Pending: in step 2")]
+fn expect_ready_appends_custom_messages() {
+    let poll: Poll<i32> = Poll::Pending;
+    expect_ready!(poll, "in step {}", 2);
+}
+
+#[test]
+fn expect_pending_accepts_pending() {
+    expect_pending!(Poll::<i32>::Pending);
+}
+
+#[test]
+fn expect_pending_panics_for_ready_values() {
+    assert_panics_with(
+        "expect_pending!: expected `poll` to be Pending, got Ready(Ok(42))",
+        || {
+            let poll: Poll<Result<i32, String>> = Poll::Ready(Ok(42));
+            expect_pending!(poll);
+        },
+    );
+}
+
+#[test]
+#[should_panic(
+    expected = "expect_pending!: expected `poll` to be Pending, got Ready(42): in step 3"
+)]
+fn expect_pending_appends_custom_messages() {
+    let poll = Poll::Ready(42);
+    expect_pending!(poll, "in step {}", 3);
+}
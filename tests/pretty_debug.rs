@@ -0,0 +1,36 @@
+#![cfg(feature = "pretty-debug")]
+
+use std::env;
+use unsafe_get::{assert_variant_eq, get, get_result};
+
+#[allow(dead_code)]
+#[derive(Debug)]
+enum Enum {
+    Foo { foo: i32 },
+    Bar { bar: Vec<i32> },
+}
+
+#[test]
+#[should_panic(
+    expected = "get!: expected enum constructor: Enum::Foo, got Bar {\n    bar: [\n        1,\n        2,\n    ],\n}"
+)]
+fn formats_values_over_multiple_lines() {
+    get!(Enum::Bar { bar: vec![1, 2] }, Enum::Foo, foo);
+}
+
+#[test]
+#[should_panic(
+    expected = "assert_variant_eq!: fields of Enum::Bar differ\n  field `bar`: Diff < left / right > :\n     [\n         1,\n    <    2,\n    >    3,\n     ]"
+)]
+fn shows_multi_line_field_differences_as_a_diff() {
+    // The `color` feature colors the differing lines.
+    env::set_var("NO_COLOR", "1");
+    assert_variant_eq!(Enum::Bar { bar: vec![1, 2] }, Enum::Bar { bar: vec![1, 3] });
+}
+
+#[test]
+fn formats_error_values_over_multiple_lines() {
+    let error = get_result!(Enum::Bar { bar: vec![1] }, Enum::Foo, foo).unwrap_err();
+    assert_eq!(error.actual, "Bar");
+    assert_eq!(error.value, "Bar {\n    bar: [\n        1,\n    ],\n}");
+}
@@ -0,0 +1,81 @@
+use unsafe_get::project;
+
+#[derive(Debug)]
+enum Enum {
+    Big {
+        a: i32,
+        b: String,
+        c: bool,
+        d: Vec<u8>,
+    },
+    Small,
+}
+
+fn big() -> Enum {
+    Enum::Big {
+        a: 1,
+        b: "foo".to_string(),
+        c: true,
+        d: vec![1, 2],
+    }
+}
+
+#[test]
+fn projects_fields_by_reference() {
+    let value = big();
+    let projection = project!(&value, Enum::Big, a, b, d);
+    assert_eq!(projection.a, &1);
+    assert_eq!(projection.b, "foo");
+    assert_eq!(projection.d, &vec![1, 2]);
+    assert_eq!(
+        format!("{:?}", projection),
+        "Projection { a: 1, b: \"foo\", d: [1, 2] }"
+    );
+}
+
+#[test]
+fn projects_owned_fields() {
+    let projection = project!(big(), Enum::Big, b, c);
+    assert_eq!(projection.b, "foo".to_string());
+    assert!(projection.c);
+}
+
+#[test]
+#[should_panic(expected = "get!: expected enum constructor: Enum::Big, got Small")]
+fn panics_in_case_of_getting_passed_in_the_wrong_enum_constructor() {
+    project!(Enum::Small, Enum::Big, a);
+}
+
+#[cfg(feature = "derive")]
+mod all_fields {
+    use unsafe_get::{project, VariantRefs};
+
+    #[derive(Debug, VariantRefs)]
+    #[variant_refs(derive(Debug))]
+    enum Enum {
+        Big { a: i32, b: String },
+        Pair(i32, bool),
+        Small,
+    }
+
+    #[test]
+    fn projects_all_fields_by_reference() {
+        let value = Enum::Big {
+            a: 1,
+            b: "foo".to_string(),
+        };
+        let projection = project!(&value, Enum::Big);
+        assert_eq!(projection.a, &1);
+        assert_eq!(projection.b, "foo");
+        let projection = project!(&Enum::Pair(2, true), Enum::Pair);
+        assert_eq!((projection.0, projection.1), (&2, &true));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "get!: expected enum constructor: Enum::Big, got Small while matching `&Enum::Small`"
+    )]
+    fn panics_for_other_constructors() {
+        project!(&Enum::Small, Enum::Big);
+    }
+}
@@ -0,0 +1,57 @@
+mod common;
+
+use common::assert_panics_with;
+use std::sync::mpsc::channel;
+use std::time::Duration;
+use unsafe_get::recv_variant;
+
+#[allow(dead_code)]
+#[derive(Debug)]
+enum Enum {
+    Foo { foo: i32, bar: bool },
+    Pair(i32, i32),
+}
+
+const TIMEOUT: Duration = Duration::from_millis(10);
+
+#[test]
+fn binds_fields_of_received_messages() {
+    let (sender, receiver) = channel();
+    sender.send(Enum::Foo { foo: 42, bar: true }).unwrap();
+    sender.send(Enum::Pair(1, 2)).unwrap();
+    recv_variant!(receiver, TIMEOUT, Enum::Foo { foo, bar });
+    assert_eq!((foo, bar), (42, true));
+    recv_variant!(receiver, TIMEOUT, Enum::Pair(a, b));
+    assert_eq!((a, b), (1, 2));
+}
+
+#[test]
+fn panics_for_mismatching_messages() {
+    assert_panics_with(
+        "recv_variant!: expected pattern: Enum::Pair(..), got Foo { foo: 42, bar: true }",
+        || {
+            let (sender, receiver) = channel();
+            sender.send(Enum::Foo { foo: 42, bar: true }).unwrap();
+            recv_variant!(receiver, TIMEOUT, Enum::Pair(..));
+        },
+    );
+}
+
+#[test]
+#[should_panic(
+    expected = "recv_variant!: expected pattern: Enum::Pair(..), but timed out after 10ms: in step 2"
+)]
+fn panics_on_timeouts() {
+    let (_sender, receiver) = channel::<Enum>();
+    recv_variant!(receiver, TIMEOUT, Enum::Pair(..), "in step {}", 2);
+}
+
+#[test]
+#[should_panic(
+    expected = "recv_variant!: expected pattern: Enum::Pair(..), but the channel is disconnected"
+)]
+fn panics_for_disconnected_channels() {
+    let (sender, receiver) = channel::<Enum>();
+    drop(sender);
+    recv_variant!(receiver, TIMEOUT, Enum::Pair(..));
+}
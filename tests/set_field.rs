@@ -0,0 +1,51 @@
+mod common;
+
+use common::assert_panics_with;
+use unsafe_get::set_field;
+
+#[derive(Debug, PartialEq)]
+enum Enum {
+    Foo { foo: i32, bar: String },
+    Pair(i32, i32),
+}
+
+#[test]
+fn overwrites_fields() {
+    let mut value = Enum::Foo {
+        foo: 1,
+        bar: "bar".to_string(),
+    };
+    set_field!(&mut value, Enum::Foo, foo = 42);
+    assert_eq!(
+        value,
+        Enum::Foo {
+            foo: 42,
+            bar: "bar".to_string()
+        }
+    );
+}
+
+#[test]
+fn overwrites_multiple_and_positional_fields() {
+    let mut value = Enum::Pair(1, 2);
+    set_field!(&mut value, Enum::Pair, 0 = 3, 1 = 4);
+    assert_eq!(value, Enum::Pair(3, 4));
+}
+
+#[test]
+fn panics_in_case_of_getting_passed_in_the_wrong_enum_constructor() {
+    assert_panics_with(
+        "get!: expected enum constructor: Enum::Foo, got Pair(1, 2)",
+        || {
+            let mut value = Enum::Pair(1, 2);
+            set_field!(&mut value, Enum::Foo, foo = 42);
+        },
+    );
+}
+
+#[test]
+#[should_panic(expected = "while matching `&mut value`")]
+fn names_the_given_expression_in_panic_messages() {
+    let mut value = Enum::Pair(1, 2);
+    set_field!(&mut value, Enum::Foo, foo = 42);
+}
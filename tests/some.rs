@@ -0,0 +1,20 @@
+use unsafe_get::some;
+
+#[test]
+fn returns_the_contained_value() {
+    assert_eq!(some!(Some(42)), 42);
+    assert_eq!(some!(Some("foo"), "with a message"), "foo");
+}
+
+#[test]
+#[should_panic(expected = "some!: expected `values.first()` to be Some(_), got None")]
+fn panics_with_the_expression_for_none() {
+    let values: Vec<i32> = vec![];
+    some!(values.first());
+}
+
+#[test]
+#[should_panic(expected = "some!: expected `None::<i32>` to be Some(_), got None: in case 3")]
+fn appends_custom_messages() {
+    some!(None::<i32>, "in case {}", 3);
+}
@@ -0,0 +1,20 @@
+use unsafe_get::static_assert_variant;
+
+#[allow(dead_code)]
+enum Enum {
+    Foo { foo: i32 },
+    Pair(i32, i32),
+    Bar,
+}
+
+const VALUES: [Enum; 3] = [Enum::Foo { foo: 1 }, Enum::Pair(1, 2), Enum::Bar];
+
+static_assert_variant!(VALUES[0], Enum::Foo);
+static_assert_variant!(VALUES[1], Enum::Pair);
+static_assert_variant!(VALUES[2], Enum::Bar,);
+
+#[test]
+fn can_be_used_in_functions() {
+    const VALUE: Enum = Enum::Pair(3, 4);
+    static_assert_variant!(VALUE, Enum::Pair);
+}
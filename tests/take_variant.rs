@@ -0,0 +1,60 @@
+use unsafe_get::take_variant;
+
+#[derive(Debug, PartialEq)]
+struct NoDefault(i32);
+
+#[derive(Debug, PartialEq)]
+enum State {
+    Loaded { data: Vec<i32>, other: NoDefault },
+    Loading,
+}
+
+fn loaded() -> State {
+    State::Loaded {
+        data: vec![1, 2],
+        other: NoDefault(1),
+    }
+}
+
+#[test]
+fn moves_fields_out_leaving_default_values() {
+    let mut state = loaded();
+    assert_eq!(take_variant!(&mut state, State::Loaded, data), vec![1, 2]);
+    assert_eq!(
+        state,
+        State::Loaded {
+            data: vec![],
+            other: NoDefault(1)
+        }
+    );
+}
+
+#[test]
+fn moves_fields_out_leaving_placeholders() {
+    let mut state = loaded();
+    assert_eq!(
+        take_variant!(&mut state, State::Loaded, other, NoDefault(2)),
+        NoDefault(1)
+    );
+    assert_eq!(
+        state,
+        State::Loaded {
+            data: vec![1, 2],
+            other: NoDefault(2)
+        }
+    );
+}
+
+#[test]
+#[should_panic(expected = "get!: expected enum constructor: State::Loaded, got Loading")]
+fn panics_in_case_of_getting_passed_in_the_wrong_enum_constructor() {
+    let mut state = State::Loading;
+    take_variant!(&mut state, State::Loaded, data);
+}
+
+#[test]
+#[should_panic(expected = "while matching `&mut state`")]
+fn names_the_given_expression_in_panic_messages() {
+    let mut state = State::Loading;
+    take_variant!(&mut state, State::Loaded, data);
+}
@@ -0,0 +1,21 @@
+#![cfg(feature = "test-only")]
+
+use unsafe_get::{get, let_assert, unwrap_match};
+
+#[derive(Debug)]
+enum Enum {
+    Foo { foo: i32 },
+}
+
+#[test]
+fn allows_the_macros_in_tests() {
+    assert_eq!(get!(Enum::Foo { foo: 42 }, Enum::Foo, foo), 42);
+    assert_eq!(
+        unwrap_match!(Enum::Foo { foo: 42 }, Enum::Foo { foo } => foo),
+        42
+    );
+    let_assert!(Enum::Foo { foo } = Enum::Foo { foo: 42 });
+    assert_eq!(foo, 42);
+    const FOO: i32 = get!(const Enum::Foo { foo: 42 }, Enum::Foo, foo);
+    assert_eq!(FOO, 42);
+}
@@ -0,0 +1,37 @@
+use unsafe_get::try_get;
+
+#[derive(Debug)]
+enum Enum {
+    Foo { foo: i32, bar: bool },
+    Pair(i32, i32),
+}
+
+#[test]
+fn returns_enum_fields() {
+    assert_eq!(
+        try_get!(Enum::Foo { foo: 42, bar: true }, Enum::Foo, foo),
+        Some(42)
+    );
+    assert_eq!(try_get!(Enum::Pair(1, 2), Enum::Pair, 0), Some(1));
+}
+
+#[test]
+fn returns_none_for_other_enum_constructors() {
+    assert_eq!(try_get!(Enum::Pair(1, 2), Enum::Foo, foo), None);
+}
+
+#[test]
+fn returns_multiple_fields() {
+    let value = Enum::Foo { foo: 42, bar: true };
+    assert_eq!(try_get!(&value, Enum::Foo, (foo, bar)), Some((&42, &true)));
+    assert_eq!(try_get!(value, Enum::Pair, 1), None);
+}
+
+#[test]
+fn looks_through_boxes() {
+    let value = Box::new(Enum::Pair(1, 2));
+    assert_eq!(try_get!(&value, Enum::Pair, 1), Some(&2));
+    assert_eq!(try_get!(value, Enum::Foo, foo), None);
+    let shared = std::rc::Rc::new(Enum::Pair(1, 2));
+    assert_eq!(try_get!(&shared, Enum::Pair, 0), Some(&1));
+}
@@ -0,0 +1,51 @@
+mod common;
+
+use common::squash;
+use std::error::Error;
+use unsafe_get::{try_let, PatternMismatch};
+
+#[derive(Debug)]
+enum Enum {
+    Foo { foo: i32, bar: bool },
+    Pair(i32, i32),
+}
+
+fn sum(value: Enum) -> Result<i32, PatternMismatch> {
+    try_let!(Enum::Pair(a, b) = value);
+    Ok(a + b)
+}
+
+#[test]
+fn binds_variables() {
+    assert_eq!(sum(Enum::Pair(1, 2)), Ok(3));
+}
+
+#[test]
+fn returns_errors_for_mismatches() {
+    let mut error = sum(Enum::Foo { foo: 1, bar: true }).unwrap_err();
+    error.actual = squash(&error.actual);
+    assert_eq!(
+        error,
+        PatternMismatch {
+            expression: "value",
+            pattern: "Enum::Pair(a, b)",
+            actual: squash("Foo { foo: 1, bar: true }"),
+        }
+    );
+}
+
+#[test]
+fn converts_errors() -> Result<(), Box<dyn Error>> {
+    let value = Enum::Foo { foo: 42, bar: true };
+    try_let!(Enum::Foo { foo, bar: true } = &value);
+    assert_eq!(foo, &42);
+    let result: Result<(), Box<dyn Error>> = (|| {
+        try_let!(Enum::Foo { bar: false, .. } = &value);
+        Ok(())
+    })();
+    assert_eq!(
+        squash(&result.unwrap_err().to_string()),
+        squash("expected `&value` to match pattern: Enum::Foo { bar: false, .. }, got Foo { foo: 42, bar: true }")
+    );
+    Ok(())
+}
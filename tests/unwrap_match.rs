@@ -0,0 +1,42 @@
+mod common;
+
+use common::assert_panics_with;
+use unsafe_get::unwrap_match;
+
+#[derive(Debug)]
+enum Enum {
+    Foo { a: i32, b: i32 },
+    Bar(Option<i32>),
+}
+
+#[test]
+fn evaluates_the_expression_for_matching_values() {
+    assert_eq!(
+        unwrap_match!(Enum::Foo { a: 1, b: 2 }, Enum::Foo { a, b } => a + b),
+        3
+    );
+    assert_eq!(
+        unwrap_match!(&Enum::Bar(Some(42)), Enum::Bar(Some(n)) if *n > 0 => *n),
+        42
+    );
+}
+
+#[test]
+fn panics_for_values_that_dont_match() {
+    assert_panics_with(
+        "unwrap_match!: expected pattern: Enum::Bar(Some(_)), got Bar(None)",
+        || {
+            unwrap_match!(Enum::Bar(None), Enum::Bar(Some(_)) => ());
+        },
+    );
+}
+
+#[test]
+fn panics_for_values_that_dont_satisfy_the_guard() {
+    assert_panics_with(
+        "unwrap_match!: expected pattern: Enum::Foo { a, .. } if a > 1, got Foo { a: 1, b: 2 }",
+        || {
+            unwrap_match!(Enum::Foo { a: 1, b: 2 }, Enum::Foo { a, .. } if a > 1 => a);
+        },
+    );
+}
@@ -0,0 +1,40 @@
+use std::fmt;
+use unsafe_get::variant_name;
+
+#[allow(dead_code)]
+#[derive(Debug)]
+enum Enum {
+    Foo { foo: i32 },
+    Pair(i32, i32),
+    Done,
+}
+
+#[test]
+fn returns_the_name_of_the_variant() {
+    assert_eq!(variant_name!(Enum::Foo { foo: 42 }), "Foo");
+    assert_eq!(variant_name!(Enum::Pair(1, 2)), "Pair");
+    assert_eq!(variant_name!(Enum::Done), "Done");
+}
+
+#[test]
+fn works_through_references_and_boxes() {
+    let value = Box::new(Enum::Foo { foo: 42 });
+    assert_eq!(variant_name!(&value), "Foo");
+    let name: String = variant_name!(value);
+    assert_eq!(name, "Foo");
+}
+
+struct Huge;
+
+impl fmt::Debug for Huge {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("Huge")?;
+        formatter.write_str(" { ")?;
+        panic!("formatted past the variant name")
+    }
+}
+
+#[test]
+fn stops_formatting_after_the_variant_name() {
+    assert_eq!(variant_name!(Huge), "Huge");
+}
@@ -0,0 +1,42 @@
+use unsafe_get::variant_where;
+
+#[derive(Debug)]
+enum Enum {
+    Foo { foo: i32 },
+    Bar(i32),
+    Baz,
+}
+
+fn count(values: &[Enum], predicate: impl Fn(&Enum) -> bool) -> usize {
+    values.iter().filter(|value| predicate(value)).count()
+}
+
+fn values() -> [Enum; 4] {
+    [Enum::Foo { foo: 1 }, Enum::Bar(2), Enum::Baz, Enum::Bar(3)]
+}
+
+#[test]
+fn matches_patterns() {
+    assert_eq!(count(&values(), variant_where!(Enum::Bar(3))), 1);
+    assert_eq!(
+        count(&values(), variant_where!(Enum::Foo { .. } | Enum::Baz)),
+        2
+    );
+}
+
+#[test]
+fn supports_guards() {
+    assert_eq!(count(&values(), variant_where!(Enum::Bar(n) if *n > 1)), 2);
+    assert_eq!(
+        count(&values(), variant_where!(Enum::Foo { foo } if *foo > 1)),
+        0
+    );
+}
+
+#[test]
+fn can_capture_variables_by_value() {
+    fn greater_than(limit: i32) -> impl Fn(&Enum) -> bool {
+        variant_where!(move Enum::Bar(n) if *n > limit)
+    }
+    assert_eq!(count(&values(), greater_than(2)), 1);
+}
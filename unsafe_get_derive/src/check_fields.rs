@@ -0,0 +1,62 @@
+use crate::checked_fields::{field_const, registry_module};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{parenthesized, Member, Path, Token};
+
+/// The arguments of `check_fields!`: the constructor and optionally the
+/// fields, followed by anything else `get!` accepts, which is ignored.
+pub struct Arguments {
+    constructor: Path,
+    fields: Vec<Member>,
+}
+
+impl Parse for Arguments {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let constructor: Path = input.parse()?;
+        if constructor.segments.len() < 2 {
+            return Err(syn::Error::new_spanned(
+                &constructor,
+                "checked_get! expects a constructor of the form `Enum::Variant`",
+            ));
+        }
+        let mut fields = Vec::new();
+        if input.parse::<Option<Token![,]>>()?.is_some() {
+            if input.peek(syn::token::Paren) {
+                let content;
+                parenthesized!(content in input);
+                fields.extend(Punctuated::<Member, Token![,]>::parse_terminated(&content)?);
+            } else if input.peek(syn::Ident) || input.peek(syn::LitInt) {
+                fields.push(input.parse()?);
+            }
+        }
+        input.parse::<TokenStream>()?;
+        Ok(Arguments {
+            constructor,
+            fields,
+        })
+    }
+}
+
+/// Expands to statements that refer to the constants generated by
+/// `CheckedFields` for the variant and the fields.
+pub fn expand(arguments: Arguments) -> TokenStream {
+    let constructor = &arguments.constructor;
+    let count = constructor.segments.len();
+    let variant = &constructor.segments[count - 1].ident;
+    let enum_name = &constructor.segments[count - 2].ident;
+    let mut registry = constructor.clone();
+    registry.segments = constructor
+        .segments
+        .iter()
+        .take(count - 2)
+        .cloned()
+        .collect();
+    registry.segments.push(registry_module(enum_name).into());
+    let fields = arguments.fields.iter().map(field_const);
+    quote! {
+        let () = #registry::#variant::__variant;
+        #(let () = #registry::#variant::#fields;)*
+    }
+}
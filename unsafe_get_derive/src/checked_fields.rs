@@ -0,0 +1,44 @@
+use crate::variants::enum_data;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{DeriveInput, Ident, Member};
+
+pub fn derive(input: DeriveInput) -> syn::Result<TokenStream> {
+    let data = enum_data(&input, "CheckedFields")?;
+    let vis = &input.vis;
+    let registry = registry_module(&input.ident);
+    let variants = data.variants.iter().map(|variant| {
+        let ident = &variant.ident;
+        let fields = variant.fields.members().map(|member| field_const(&member));
+        quote! {
+            pub mod #ident {
+                pub const __variant: () = ();
+                #(pub const #fields: () = ();)*
+            }
+        }
+    });
+    Ok(quote! {
+        #[doc(hidden)]
+        #[allow(dead_code, non_snake_case, non_upper_case_globals)]
+        #vis mod #registry {
+            #(#variants)*
+        }
+    })
+}
+
+/// The name of the module that `CheckedFields` generates next to an enum.
+/// It contains a module per variant with a constant per field, so that
+/// `checked_get!` can check that variants and fields exist by referring to
+/// these constants, and rustc suggests similar names on typos.
+pub fn registry_module(enum_name: &Ident) -> Ident {
+    format_ident!("__unsafe_get_{}", enum_name)
+}
+
+/// The name of the constant for a field. Fields of tuple variants are
+/// prefixed with an underscore, since their indices aren't identifiers.
+pub fn field_const(member: &Member) -> Ident {
+    match member {
+        Member::Named(ident) => ident.clone(),
+        Member::Unnamed(index) => format_ident!("_{}", index.index, span = index.span),
+    }
+}
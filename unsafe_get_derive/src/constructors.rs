@@ -0,0 +1,94 @@
+use crate::variants::{enum_data, snake_case, VariantFields};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use std::collections::BTreeMap;
+use syn::{DeriveInput, Fields, Ident};
+
+pub fn derive(input: DeriveInput) -> syn::Result<TokenStream> {
+    let data = enum_data(&input, "Constructors")?;
+    let name = &input.ident;
+    let vis = &input.vis;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    let mut names = BTreeMap::new();
+    let mut methods = Vec::new();
+    for variant in &data.variants {
+        let fields = VariantFields::new(variant);
+        let variant_ident = &variant.ident;
+        let method_name = snake_case(variant_ident);
+        let method = method_ident(&method_name, variant_ident)?;
+        let default_name = format!("{}_default", method_name);
+        let mut generated = vec![&method_name];
+        if !matches!(variant.fields, Fields::Unit) {
+            generated.push(&default_name);
+        }
+        for generated_name in generated {
+            if let Some(other) = names.insert(generated_name.clone(), variant_ident) {
+                return Err(syn::Error::new_spanned(
+                    variant_ident,
+                    format!(
+                        "the constructor `{}` for `{}` collides with the one for `{}`",
+                        generated_name, variant_ident, other
+                    ),
+                ));
+            }
+        }
+        let members = &fields.members;
+        let bindings = &fields.bindings;
+        let types = &fields.types;
+        let constructor = format!("{}::{}", name, variant_ident);
+        let doc = format!(
+            "Constructs a `{}` from its fields, in declaration order.",
+            constructor
+        );
+        let default = if let Fields::Unit = variant.fields {
+            quote!()
+        } else {
+            let method = format_ident!("{}", default_name);
+            let doc = format!(
+                "Constructs a `{}` with all fields set to their default values.",
+                constructor
+            );
+            // The higher-ranked bounds are only checked when the method is
+            // called, so they don't cause errors for fields that don't
+            // implement `Default`.
+            quote! {
+                #[doc = #doc]
+                #vis fn #method() -> Self
+                where
+                    #(for<'default> #types: ::core::default::Default,)*
+                {
+                    Self::#variant_ident { #(#members: ::core::default::Default::default(),)* }
+                }
+            }
+        };
+        methods.push(quote! {
+            #[doc = #doc]
+            #[allow(clippy::too_many_arguments)]
+            #vis fn #method(#(#bindings: #types),*) -> Self {
+                Self::#variant_ident { #(#members: #bindings,)* }
+            }
+
+            #default
+        });
+    }
+    Ok(quote! {
+        impl #impl_generics #name #type_generics #where_clause {
+            #(#methods)*
+        }
+    })
+}
+
+/// Returns the identifier for a constructor method. Names that are keywords,
+/// like `type` for a variant `Type`, are emitted as raw identifiers.
+fn method_ident(name: &str, variant: &Ident) -> syn::Result<Ident> {
+    if syn::parse_str::<Ident>(name).is_ok() {
+        Ok(Ident::new(name, variant.span()))
+    } else if ["crate", "self", "super"].contains(&name) {
+        Err(syn::Error::new_spanned(
+            variant,
+            format!("`{}` can't be used as the name of a constructor", name),
+        ))
+    } else {
+        Ok(Ident::new_raw(name, variant.span()))
+    }
+}
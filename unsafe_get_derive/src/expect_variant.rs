@@ -0,0 +1,50 @@
+use crate::variants::{enum_data, snake_case, VariantFields};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::DeriveInput;
+
+pub fn derive(input: DeriveInput) -> syn::Result<TokenStream> {
+    let data = enum_data(&input, "ExpectVariant")?;
+    let name = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    let methods = data.variants.iter().map(|variant| {
+        let fields = VariantFields::new(variant);
+        let expect = format_ident!("expect_{}", snake_case(&variant.ident));
+        let pattern = fields.pattern();
+        let values = fields.values();
+        let values_type = fields.values_type(|ty| quote!(#ty));
+        let constructor = format!("{}::{}", name, variant.ident);
+        let doc = format!(
+            "Returns the fields of `self`. Panics if it's not constructed with `{}`.",
+            constructor
+        );
+        let message = format!(
+            "{}::{}: expected enum constructor: {}, got {{}}",
+            name, expect, constructor
+        );
+        quote! {
+            #[doc = #doc]
+            #[track_caller]
+            pub fn #expect(self) -> #values_type {
+                match self {
+                    #pattern => #values,
+                    #[allow(unreachable_patterns)]
+                    value => ::unsafe_get::__private::report_caller(
+                        ::unsafe_get::__private::Failure {
+                            expected: #constructor,
+                            actual: ::unsafe_get::get!(@variant value),
+                            value: ::unsafe_get::get!(@actual value),
+                            description: ::std::format!(#message, ::unsafe_get::get!(@actual value)),
+                        },
+                        "self",
+                    ),
+                }
+            }
+        }
+    });
+    Ok(quote! {
+        impl #impl_generics #name #type_generics #where_clause {
+            #(#methods)*
+        }
+    })
+}
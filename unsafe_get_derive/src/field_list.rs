@@ -0,0 +1,36 @@
+use crate::render::render;
+use crate::variants::enum_data;
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::{DeriveInput, Member};
+
+pub fn derive(input: DeriveInput) -> syn::Result<TokenStream> {
+    let data = enum_data(&input, "FieldList")?;
+    let name = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    let arms = data.variants.iter().map(|variant| {
+        let ident = &variant.ident;
+        let fields = variant
+            .fields
+            .iter()
+            .zip(variant.fields.members())
+            .map(|(field, member)| {
+                let member = match member {
+                    Member::Named(ident) => ident.to_string(),
+                    Member::Unnamed(index) => index.index.to_string(),
+                };
+                let ty = render(&field.ty.to_token_stream());
+                quote!((#member, #ty))
+            });
+        quote!(Self::#ident { .. } => &[#(#fields),*],)
+    });
+    Ok(quote! {
+        impl #impl_generics ::unsafe_get::FieldList for #name #type_generics #where_clause {
+            fn field_list(&self) -> &'static [(&'static str, &'static str)] {
+                match *self {
+                    #(#arms)*
+                }
+            }
+        }
+    })
+}
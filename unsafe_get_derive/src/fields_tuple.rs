@@ -0,0 +1,67 @@
+use crate::variants::{enum_data, snake_case, VariantFields};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::DeriveInput;
+
+pub fn derive(input: DeriveInput) -> syn::Result<TokenStream> {
+    let data = enum_data(&input, "FieldsTuple")?;
+    let name = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    let methods = data.variants.iter().map(|variant| {
+        let fields = VariantFields::new(variant);
+        let snake_case = snake_case(&variant.ident);
+        let into = format_ident!("into_{}_tuple", snake_case);
+        let expect = format_ident!("expect_{}_tuple", snake_case);
+        let pattern = fields.pattern();
+        let bindings = &fields.bindings;
+        let types = &fields.types;
+        let tuple = quote!((#(#bindings,)*));
+        let tuple_type = quote!((#(#types,)*));
+        let constructor = format!("{}::{}", name, variant.ident);
+        let into_doc = format!(
+            "Returns the fields of `self` as a tuple if it's constructed with `{}`.",
+            constructor
+        );
+        let expect_doc = format!(
+            "Returns the fields of `self` as a tuple. Panics if it's not constructed with `{}`.",
+            constructor
+        );
+        let message = format!(
+            "{}::{}: expected enum constructor: {}, got {{}}",
+            name, expect, constructor
+        );
+        quote! {
+            #[doc = #into_doc]
+            pub fn #into(self) -> ::core::option::Option<#tuple_type> {
+                match self {
+                    #pattern => ::core::option::Option::Some(#tuple),
+                    #[allow(unreachable_patterns)]
+                    _ => ::core::option::Option::None,
+                }
+            }
+
+            #[doc = #expect_doc]
+            #[track_caller]
+            pub fn #expect(self) -> #tuple_type {
+                match self {
+                    #pattern => #tuple,
+                    #[allow(unreachable_patterns)]
+                    value => ::unsafe_get::__private::report_caller(
+                        ::unsafe_get::__private::Failure {
+                            expected: #constructor,
+                            actual: ::unsafe_get::get!(@variant value),
+                            value: ::unsafe_get::get!(@actual value),
+                            description: ::std::format!(#message, ::unsafe_get::get!(@actual value)),
+                        },
+                        "self",
+                    ),
+                }
+            }
+        }
+    });
+    Ok(quote! {
+        impl #impl_generics #name #type_generics #where_clause {
+            #(#methods)*
+        }
+    })
+}
@@ -0,0 +1,213 @@
+use crate::render::render;
+use proc_macro2::{Span, TokenStream, TokenTree};
+use quote::{quote, quote_spanned};
+use syn::ext::IdentExt;
+use syn::parse::{Parse, ParseStream, Parser};
+use syn::{Block, Expr, Ident, Lit, Pat, Token};
+
+/// The arguments of `let_assert!`: one or more bindings, separated by
+/// commas.
+pub struct Arguments {
+    bindings: Vec<Binding>,
+}
+
+impl Parse for Arguments {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut bindings = Vec::new();
+        loop {
+            let binding: Binding = input.parse()?;
+            if binding.body.is_some() && !input.is_empty() {
+                return Err(input.error("expected the body after `=>` to come last"));
+            }
+            bindings.push(binding);
+            if input.is_empty() {
+                return Ok(Arguments { bindings });
+            }
+        }
+    }
+}
+
+/// A pattern, an expression and optionally either an `else` block or a
+/// guard, a body and a custom message. The pattern, the expression and the
+/// guard are kept as the tokens the user wrote, so that they can be rendered
+/// with their original spacing.
+struct Binding {
+    pattern: TokenStream,
+    expression: TokenStream,
+    fallback: Option<Block>,
+    guard: Option<TokenStream>,
+    body: Option<Expr>,
+    message: Option<TokenStream>,
+}
+
+impl Parse for Binding {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let pattern = raw_tokens(input, |input| {
+            Pat::parse_multi_with_leading_vert(input).map(drop)
+        })?;
+        input.parse::<Token![=]>()?;
+        let expression = raw_tokens(input, |input| input.parse::<Expr>().map(drop))?;
+        let mut fallback = None;
+        let mut guard = None;
+        let mut body = None;
+        let mut message = None;
+        let mut separated = input.parse::<Option<Token![,]>>()?.is_some();
+        if separated && input.peek(Token![else]) {
+            input.parse::<Token![else]>()?;
+            fallback = Some(input.parse()?);
+            separated = input.parse::<Option<Token![,]>>()?.is_some();
+        } else {
+            if separated && input.peek(Token![if]) {
+                input.parse::<Token![if]>()?;
+                guard = Some(raw_tokens(input, |input| input.parse::<Expr>().map(drop))?);
+                separated = input.parse::<Option<Token![,]>>()?.is_some();
+            }
+            if !separated && input.peek(Token![=>]) {
+                input.parse::<Token![=>]>()?;
+                body = Some(input.parse()?);
+                separated = input.parse::<Option<Token![,]>>()?.is_some();
+            }
+        }
+        // A format string ends the arguments, anything else after a comma
+        // is the next binding.
+        if separated && fallback.is_none() && input.peek(Lit) {
+            message = Some(input.parse()?);
+        } else if !separated && !input.is_empty() {
+            return Err(input.error("expected `,`"));
+        }
+        Ok(Binding {
+            pattern,
+            expression,
+            fallback,
+            guard,
+            body,
+            message,
+        })
+    }
+}
+
+/// Runs `parser` on a fork of `input` and returns the tokens it consumed.
+fn raw_tokens(
+    input: ParseStream,
+    parser: impl FnOnce(ParseStream) -> syn::Result<()>,
+) -> syn::Result<TokenStream> {
+    let fork = input.fork();
+    parser(&fork)?;
+    let end = fork.cursor();
+    let mut tokens = TokenStream::new();
+    while input.cursor() != end {
+        tokens.extend([input.parse::<TokenTree>()?]);
+    }
+    Ok(tokens)
+}
+
+/// Expands to the same statements as the declarative `let_assert!`.
+pub fn expand(arguments: Arguments) -> TokenStream {
+    let body = arguments
+        .bindings
+        .last()
+        .and_then(|binding| binding.body.clone());
+    let statements: TokenStream = arguments.bindings.into_iter().map(expand_binding).collect();
+    match body {
+        None => statements,
+        Some(body) => quote!({ #statements #body }),
+    }
+}
+
+fn expand_binding(binding: Binding) -> TokenStream {
+    let Binding {
+        pattern,
+        expression,
+        fallback,
+        guard,
+        message,
+        ..
+    } = binding;
+    if let Some(fallback) = fallback {
+        return quote! {
+            ::unsafe_get::__test_only!("let_assert!");
+            #[allow(irrefutable_let_patterns, unused_parens)]
+            let (#pattern) = #expression else #fallback;
+        };
+    }
+    let pattern_text = render(&pattern);
+    let expression_text = render(&expression);
+    let value = quote_spanned!(Span::mixed_site()=> value);
+    let actual = quote_spanned!(Span::mixed_site()=> actual);
+    let variant = quote_spanned!(Span::mixed_site()=> variant);
+    let describe = |problem: TokenStream, details: TokenStream| {
+        let description = quote! {
+            ::std::format!(
+                "let_assert!: {}\n  expression: {}\n       value: {}\n     pattern: {}{}",
+                #problem,
+                #expression_text,
+                #actual,
+                #pattern_text,
+                #details
+            )
+        };
+        match &message {
+            None => description,
+            Some(message) => quote! {
+                ::std::format!("{}\n{}", #description, ::core::format_args!(#message))
+            },
+        }
+    };
+    let report = |description: TokenStream| {
+        quote! {
+            ::unsafe_get::get!(
+                @report [#expression_text, #pattern_text, #variant, ::std::clone::Clone::clone(&#actual)]
+                #description
+            )
+        }
+    };
+    let problem = quote_spanned!(Span::mixed_site()=> problem);
+    let mismatch = report(describe(quote!(#problem), quote!("")));
+    let mismatch = quote! {
+        let #problem = ::unsafe_get::__private::pattern_mismatch(
+            #pattern_text,
+            ::core::option::Option::as_deref(&#variant),
+        );
+        #mismatch
+    };
+    // Variables are matched against in place, like in a `let` statement, so
+    // that `ref` bindings borrow from them.
+    let (binding, value) = match Ident::parse_any.parse2(expression.clone()) {
+        Ok(variable) => (quote!(), quote!(#variable)),
+        Err(_) => (
+            quote!(let #value = ::unsafe_get::get!(@peel #expression);),
+            value,
+        ),
+    };
+    match guard {
+        None => quote! {
+            ::unsafe_get::__test_only!("let_assert!");
+            #binding
+            #[allow(irrefutable_let_patterns, unused_parens)]
+            let (#pattern) = #value else {
+                let #actual = ::unsafe_get::get!(@actual #value);
+                let #variant = ::unsafe_get::get!(@variant #value);
+                #mismatch
+            };
+        },
+        Some(guard) => {
+            let guard_text = format!("\n       guard: {}", render(&guard));
+            let guard_failed = report(describe(quote!("guard failed"), quote!(#guard_text)));
+            // The value is moved into the bindings before the guard can be
+            // checked, so it's described up front.
+            quote! {
+                ::unsafe_get::__test_only!("let_assert!");
+                #binding
+                let #actual = ::unsafe_get::get!(@actual #value);
+                let #variant = ::unsafe_get::get!(@variant #value);
+                #[allow(irrefutable_let_patterns, unused_parens)]
+                let (#pattern) = #value else {
+                    #mismatch
+                };
+                if !(#guard) {
+                    #guard_failed
+                }
+            }
+        }
+    }
+}
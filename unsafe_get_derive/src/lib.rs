@@ -0,0 +1,684 @@
+//! Derive macros for [`unsafe_get`](https://docs.rs/unsafe_get). Use them
+//! through the `derive` feature of `unsafe_get` instead of depending on this
+//! crate directly.
+
+extern crate proc_macro;
+
+mod check_fields;
+mod checked_fields;
+mod constructors;
+mod expect_variant;
+mod field_list;
+mod fields_tuple;
+mod let_assert;
+mod partial_variant_eq;
+mod prism;
+mod project_variant;
+mod redacted_debug;
+mod render;
+mod try_into_variant;
+mod unwrap;
+mod variant_access;
+mod variant_kind;
+mod variant_list;
+mod variant_name;
+mod variant_refs;
+mod variant_structs;
+mod variant_test;
+mod variants;
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput, ItemFn};
+
+/// `#[derive(VariantAccess)]` generates checked accessors for every variant
+/// of an enum. For a variant `Foo` it generates:
+///
+/// - `is_foo(&self) -> bool`,
+/// - `as_foo(&self) -> Option<..>`, returning references to the fields,
+/// - `into_foo(self) -> Option<..>`, returning the fields.
+///
+/// Variants with one field return that field, variants with multiple fields
+/// return a tuple and unit variants return `()`:
+///
+/// ```
+/// use unsafe_get::VariantAccess;
+///
+/// #[derive(VariantAccess)]
+/// enum Shape {
+///   Circle { radius: f64 },
+///   Rectangle(f64, f64),
+///   Empty,
+/// }
+///
+/// let shape = Shape::Rectangle(2.0, 3.0);
+/// assert!(shape.is_rectangle());
+/// assert_eq!(shape.as_rectangle(), Some((&2.0, &3.0)));
+/// assert_eq!(shape.as_circle(), None);
+/// assert_eq!(Shape::Circle { radius: 1.0 }.into_circle(), Some(1.0));
+/// assert_eq!(Shape::Empty.into_empty(), Some(()));
+/// ```
+///
+/// Variant names are converted to snake case with acronyms kept together,
+/// so the methods for a variant `HTTPError` are `is_http_error` etc. The
+/// other derives name their methods the same way.
+#[proc_macro_derive(VariantAccess)]
+pub fn derive_variant_access(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    variant_access::derive(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// `#[derive(ExpectVariant)]` generates a panicking accessor for every
+/// variant of an enum. For a variant `Foo` it generates
+/// `expect_foo(self) -> ..`, which returns the fields like the `into_*`
+/// methods of [`VariantAccess`](derive.VariantAccess.html), but panics with
+/// a message in the style of `get!` if the value is constructed with a
+/// different constructor:
+///
+/// ```
+/// use unsafe_get::ExpectVariant;
+///
+/// #[derive(Debug, ExpectVariant)]
+/// enum Shape {
+///   Circle { radius: f64 },
+///   Rectangle(f64, f64),
+/// }
+///
+/// assert_eq!(Shape::Rectangle(2.0, 3.0).expect_rectangle(), (2.0, 3.0));
+/// ```
+///
+/// ```should_panic
+/// # use unsafe_get::ExpectVariant;
+/// #
+/// # #[derive(Debug, ExpectVariant)]
+/// # enum Shape {
+/// #   Circle { radius: f64 },
+/// #   Rectangle(f64, f64),
+/// # }
+/// #
+/// // panics with "Shape::expect_circle: expected enum constructor: Shape::Circle, got Rectangle(2.0, 3.0)"
+/// Shape::Rectangle(2.0, 3.0).expect_circle();
+/// ```
+#[proc_macro_derive(ExpectVariant)]
+pub fn derive_expect_variant(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expect_variant::derive(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// `#[derive(TryIntoVariant)]` implements `TryFrom<Enum>` for the payload of
+/// every variant of an enum. The payload of a variant with one field is the
+/// type of that field, the payload of a variant with multiple fields is a
+/// tuple of their types. Unit variants are skipped. The error is an
+/// [`UnexpectedVariant`](struct.UnexpectedVariant.html), so the conversions
+/// compose with `?`:
+///
+/// ```
+/// use std::convert::TryFrom;
+/// use unsafe_get::{TryIntoVariant, UnexpectedVariant};
+///
+/// #[derive(Debug, TryIntoVariant)]
+/// enum Response {
+///   Text(String),
+///   Status { code: u16, reason: &'static str },
+///   Empty,
+/// }
+///
+/// assert_eq!(String::try_from(Response::Text("foo".to_string())), Ok("foo".to_string()));
+/// assert_eq!(
+///   <(u16, &str)>::try_from(Response::Status { code: 404, reason: "Not Found" }),
+///   Ok((404, "Not Found"))
+/// );
+/// assert_eq!(
+///   String::try_from(Response::Empty),
+///   Err(UnexpectedVariant {
+///     expected: "Response::Text",
+///     actual: "Empty".to_string(),
+///     value: "Empty".to_string(),
+///   })
+/// );
+/// ```
+///
+/// Since there can only be one implementation per payload type, variants
+/// with the same payload type as another variant have to be skipped with
+/// `#[try_into_variant(skip)]`.
+#[proc_macro_derive(TryIntoVariant, attributes(try_into_variant))]
+pub fn derive_try_into_variant(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    try_into_variant::derive(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// `#[derive(VariantStructs)]` generates a struct for every variant of an
+/// enum with named fields. For a variant `Foo { a: A, b: B }` it generates
+/// a struct `FooFields { a: A, b: B }` with the same visibility as the enum,
+/// plus conversions between the two:
+///
+/// - `Enum::into_foo_fields(self) -> Option<FooFields>`,
+/// - `Enum::from_foo_fields(fields: FooFields) -> Enum`,
+/// - `impl From<FooFields> for Enum`,
+/// - `impl TryFrom<Enum> for FooFields`, with
+///   [`UnexpectedVariant`](struct.UnexpectedVariant.html) as the error, so
+///   that the fields can be extracted with `?`.
+///
+/// Derives for the generated structs can be requested with
+/// `#[variant_structs(derive(..))]`:
+///
+/// ```
+/// use unsafe_get::VariantStructs;
+///
+/// #[derive(VariantStructs)]
+/// #[variant_structs(derive(Debug, PartialEq))]
+/// enum Request {
+///   Get { path: String, retries: u32 },
+///   Shutdown,
+/// }
+///
+/// let request = Request::Get { path: "/".to_string(), retries: 3 };
+/// assert_eq!(
+///   request.into_get_fields(),
+///   Some(GetFields { path: "/".to_string(), retries: 3 })
+/// );
+/// assert_eq!(Request::Shutdown.into_get_fields(), None);
+/// ```
+#[proc_macro_derive(VariantStructs, attributes(variant_structs))]
+pub fn derive_variant_structs(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    variant_structs::derive(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// `#[derive(VariantName)]` implements
+/// [`VariantName`](trait.VariantName.html), which returns the name of the
+/// constructor a value is constructed with:
+///
+/// ```
+/// use unsafe_get::VariantName;
+///
+/// #[derive(VariantName)]
+/// enum Shape {
+///   Circle { radius: f64 },
+///   Rectangle(f64, f64),
+///   Empty,
+/// }
+///
+/// assert_eq!(Shape::Circle { radius: 1.0 }.variant_name(), "Circle");
+/// assert_eq!(Shape::Empty.variant_name(), "Empty");
+/// ```
+///
+/// The macros use it to describe values in panic messages if they don't
+/// implement `Debug`.
+#[proc_macro_derive(VariantName)]
+pub fn derive_variant_name(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    variant_name::derive(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// `#[derive(VariantKind)]` generates a fieldless mirror of an enum. For an
+/// enum `Enum` it generates an enum `EnumKind` with the same visibility and
+/// one unit variant per constructor, plus a method
+/// `Enum::kind(&self) -> EnumKind`. `EnumKind` implements `Debug`, `Clone`,
+/// `Copy`, `PartialEq`, `Eq`, `PartialOrd`, `Ord` and `Hash`, so it can be
+/// compared cheaply and used as a key for grouping values by constructor:
+///
+/// ```
+/// use unsafe_get::VariantKind;
+///
+/// #[derive(VariantKind)]
+/// enum Shape {
+///   Circle { radius: f64 },
+///   Rectangle(f64, f64),
+/// }
+///
+/// assert_eq!(Shape::Rectangle(2.0, 3.0).kind(), ShapeKind::Rectangle);
+/// ```
+#[proc_macro_derive(VariantKind)]
+pub fn derive_variant_kind(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    variant_kind::derive(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// `#[derive(VariantRefs)]` generates structs holding references to the
+/// fields of a variant. For a variant `Foo { a: A, b: B }` it generates
+///
+/// - a struct `FooRef<'variant> { a: &'variant A, b: &'variant B }` and a
+///   method `as_foo_ref(&self) -> Option<FooRef>`,
+/// - a struct `FooMut<'variant> { a: &'variant mut A, b: &'variant mut B }`
+///   and a method `as_foo_mut(&mut self) -> Option<FooMut>`.
+///
+/// For tuple variants, the structs are tuple structs. Unit variants are
+/// skipped. Derives for the generated structs can be requested with
+/// `#[variant_refs(derive(..))]`:
+///
+/// ```
+/// use unsafe_get::VariantRefs;
+///
+/// #[derive(VariantRefs)]
+/// #[variant_refs(derive(Debug))]
+/// enum Document {
+///   Text { title: String, body: String },
+///   Empty,
+/// }
+///
+/// let mut document = Document::Text { title: "foo".to_string(), body: "bar".to_string() };
+/// let text = document.as_text_ref().unwrap();
+/// assert_eq!(text.title, "foo");
+/// *document.as_text_mut().unwrap().body = "baz".to_string();
+/// assert_eq!(document.as_text_ref().unwrap().body, "baz");
+/// ```
+#[proc_macro_derive(VariantRefs, attributes(variant_refs))]
+pub fn derive_variant_refs(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    variant_refs::derive(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// `#[derive(CheckedFields)]` registers the variants and fields of an enum
+/// in a hidden module next to it, so that
+/// [`checked_get!`](macro.checked_get.html) can check them at compile time.
+#[proc_macro_derive(CheckedFields)]
+pub fn derive_checked_fields(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    checked_fields::derive(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+#[doc(hidden)]
+#[proc_macro]
+pub fn check_fields(input: TokenStream) -> TokenStream {
+    let arguments = parse_macro_input!(input as check_fields::Arguments);
+    check_fields::expand(arguments).into()
+}
+
+#[doc(hidden)]
+#[proc_macro]
+pub fn let_assert(input: TokenStream) -> TokenStream {
+    let arguments = parse_macro_input!(input as let_assert::Arguments);
+    let_assert::expand(arguments).into()
+}
+
+#[doc(hidden)]
+#[proc_macro]
+pub fn project_variant(input: TokenStream) -> TokenStream {
+    let arguments = parse_macro_input!(input as project_variant::Arguments);
+    project_variant::expand(arguments)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// `#[variant_test(..)]` turns a function returning an enum into a test that
+/// asserts on the returned value. Given a constructor, it checks the
+/// constructor like [`assert_variant!`](macro.assert_variant.html). Given a
+/// constructor with fields, it compares the fields like
+/// [`assert_variant_eq!`](macro.assert_variant_eq.html):
+///
+/// ```
+/// use unsafe_get::variant_test;
+///
+/// #[derive(Debug)]
+/// enum Outcome {
+///   Success { code: i32, output: String },
+///   Failure(String),
+/// }
+///
+/// fn run() -> Outcome {
+///   Outcome::Success { code: 0, output: "done".to_string() }
+/// }
+///
+/// #[variant_test(Outcome::Success)]
+/// fn run_succeeds() -> Outcome {
+///   run()
+/// }
+///
+/// #[variant_test(Outcome::Success { code: 0, .. })]
+/// fn run_returns_code_zero() -> Outcome {
+///   run()
+/// }
+///
+/// #[variant_test(Outcome::Failure("not found"))]
+/// fn missing_files_fail() -> Outcome {
+///   Outcome::Failure("not found".to_string())
+/// }
+/// ```
+///
+/// The fields of tuple variants are compared by position. `..` is only
+/// allowed as the last field:
+///
+/// ```compile_fail
+/// use unsafe_get::variant_test;
+///
+/// #[derive(Debug)]
+/// enum Outcome {
+///   Failure(String, i32),
+/// }
+///
+/// #[variant_test(Outcome::Failure(.., 1))]
+/// fn fails_with_code_one() -> Outcome {
+///   Outcome::Failure("not found".to_string(), 1)
+/// }
+/// ```
+///
+/// Other attributes like `#[should_panic]` can be added below
+/// `#[variant_test(..)]`.
+#[proc_macro_attribute]
+pub fn variant_test(attribute: TokenStream, item: TokenStream) -> TokenStream {
+    let function = parse_macro_input!(item as ItemFn);
+    variant_test::expand(attribute.into(), function)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// `#[derive(Unwrap)]` generates panicking accessors for every variant of
+/// an enum, named like the ones of the `Unwrap` derive of `derive_more`.
+/// Unlike `derive_more`, which only generates `_ref` and `_mut` methods
+/// with `#[unwrap(ref, ref_mut)]`, it always generates all three. For a
+/// variant `Foo` it generates:
+///
+/// - `unwrap_foo(self) -> ..`, returning the fields,
+/// - `unwrap_foo_ref(&self) -> ..`, returning references to the fields,
+/// - `unwrap_foo_mut(&mut self) -> ..`, returning mutable references to the
+///   fields.
+///
+/// Variant names are converted to snake case like in `VariantAccess`, so
+/// `HTTPError` gets `unwrap_http_error`. Variants with one field return that
+/// field, variants with multiple fields return a tuple and unit variants
+/// return `()`. The methods panic if the value is constructed with a
+/// different constructor:
+///
+/// ```
+/// use unsafe_get::Unwrap;
+///
+/// #[derive(Unwrap)]
+/// enum Shape {
+///   Circle { radius: f64 },
+///   Rectangle(f64, f64),
+/// }
+///
+/// assert_eq!(Shape::Circle { radius: 1.0 }.unwrap_circle(), 1.0);
+/// let mut shape = Shape::Rectangle(2.0, 3.0);
+/// *shape.unwrap_rectangle_mut().0 = 4.0;
+/// assert_eq!(shape.unwrap_rectangle_ref(), (&4.0, &3.0));
+/// ```
+///
+/// ```should_panic
+/// # use unsafe_get::Unwrap;
+/// #
+/// # #[derive(Unwrap)]
+/// # enum Shape {
+/// #   Circle { radius: f64 },
+/// #   Rectangle(f64, f64),
+/// # }
+/// #
+/// // panics with "called `Shape::unwrap_circle()` on a `Shape::Rectangle` value"
+/// Shape::Rectangle(2.0, 3.0).unwrap_circle();
+/// ```
+#[proc_macro_derive(Unwrap)]
+pub fn derive_unwrap(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    unwrap::derive(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// `#[derive(Prism)]` generates a [`Prism`](../unsafe_get/trait.Prism.html)
+/// for every variant of an enum that has exactly one field. For a variant
+/// `Foo` it generates a type `EnumFooPrism` and a method
+/// `Enum::foo_prism()` returning it. Prisms for nested enums can be
+/// composed with `then`:
+///
+/// ```
+/// use unsafe_get::Prism;
+///
+/// #[derive(Debug, PartialEq, Prism)]
+/// enum Event {
+///   Input(Key),
+///   Resize { width: u32, height: u32 },
+/// }
+///
+/// #[derive(Debug, PartialEq, Prism)]
+/// enum Key {
+///   Char(char),
+///   Enter,
+/// }
+///
+/// let char = Event::input_prism().then(Key::char_prism());
+/// assert_eq!(char.preview(&Event::Input(Key::Char('a'))), Some(&'a'));
+/// assert_eq!(char.preview(&Event::Input(Key::Enter)), None);
+/// assert_eq!(char.review('b'), Event::Input(Key::Char('b')));
+/// ```
+#[proc_macro_derive(Prism)]
+pub fn derive_prism(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    prism::derive(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// `#[derive(FieldsTuple)]` generates accessors returning the fields of a
+/// variant as a tuple. For a variant `Foo` it generates:
+///
+/// - `into_foo_tuple(self) -> Option<(..)>`,
+/// - `expect_foo_tuple(self) -> (..)`, which panics like the methods of
+///   [`ExpectVariant`](derive.ExpectVariant.html) if the value is
+///   constructed with a different constructor.
+///
+/// In contrast to the other derives, the fields are always returned as a
+/// tuple, even for variants with one field or no fields:
+///
+/// ```
+/// use unsafe_get::FieldsTuple;
+///
+/// #[derive(Debug, FieldsTuple)]
+/// enum Shape {
+///   Circle { radius: f64 },
+///   Rectangle(f64, f64),
+/// }
+///
+/// assert_eq!(Shape::Rectangle(2.0, 3.0).expect_rectangle_tuple(), (2.0, 3.0));
+/// assert_eq!(Shape::Circle { radius: 1.0 }.into_circle_tuple(), Some((1.0,)));
+/// assert_eq!(Shape::Circle { radius: 1.0 }.into_rectangle_tuple(), None);
+/// ```
+#[proc_macro_derive(FieldsTuple)]
+pub fn derive_fields_tuple(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    fields_tuple::derive(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// `#[derive(VariantList)]` generates associated items listing the
+/// constructors of an enum:
+///
+/// - `VARIANT_COUNT: usize`,
+/// - `variant_names() -> &'static [&'static str]`,
+/// - `iter_variants() -> impl Iterator<Item = Self>`, only for enums where
+///   all constructors are fieldless.
+///
+/// That's useful for table-driven tests that should cover every
+/// constructor, and keep doing so when constructors are added:
+///
+/// ```
+/// use unsafe_get::VariantList;
+///
+/// #[derive(Debug, PartialEq, VariantList)]
+/// enum Direction {
+///   North,
+///   East,
+///   South,
+///   West,
+/// }
+///
+/// assert_eq!(Direction::VARIANT_COUNT, 4);
+/// assert_eq!(Direction::variant_names(), ["North", "East", "South", "West"]);
+/// for direction in Direction::iter_variants() {
+///   assert!(Direction::variant_names().contains(&format!("{:?}", direction).as_str()));
+/// }
+/// ```
+#[proc_macro_derive(VariantList)]
+pub fn derive_variant_list(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    variant_list::derive(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// `#[derive(PartialVariantEq)]` implements `PartialEq` like
+/// `#[derive(PartialEq)]`, but ignores fields marked with
+/// `#[variant_eq(skip)]`. Two values are equal if they're constructed with
+/// the same constructor and all fields that aren't skipped are equal. That
+/// allows comparing values with `assert_eq!` in tests, even if they contain
+/// nondeterministic fields like timestamps or ids:
+///
+/// ```
+/// use unsafe_get::PartialVariantEq;
+///
+/// #[derive(Debug, PartialVariantEq)]
+/// enum Event {
+///   Created {
+///     #[variant_eq(skip)]
+///     id: u64,
+///     name: String,
+///   },
+///   Deleted(#[variant_eq(skip)] u64),
+/// }
+///
+/// assert_eq!(
+///   Event::Created { id: 42, name: "foo".to_string() },
+///   Event::Created { id: 0, name: "foo".to_string() },
+/// );
+/// assert_ne!(
+///   Event::Created { id: 42, name: "foo".to_string() },
+///   Event::Created { id: 42, name: "bar".to_string() },
+/// );
+/// assert_eq!(Event::Deleted(1), Event::Deleted(2));
+/// ```
+#[proc_macro_derive(PartialVariantEq, attributes(variant_eq))]
+pub fn derive_partial_variant_eq(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    partial_variant_eq::derive(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// `#[derive(Constructors)]` generates a constructor function for every
+/// variant of an enum, which is useful for building test fixtures. For a
+/// variant `Foo` it generates:
+///
+/// - `Enum::foo(..) -> Self`, taking the fields in declaration order,
+/// - `Enum::foo_default() -> Self`, setting all fields to their default
+///   values. It's only generated for variants with fields and can only be
+///   called if all fields implement `Default`.
+///
+/// ```
+/// use unsafe_get::Constructors;
+///
+/// #[derive(Debug, PartialEq, Constructors)]
+/// enum Request {
+///   Get { url: String, timeout: u32, retries: u8 },
+///   Cancel,
+/// }
+///
+/// assert_eq!(
+///   Request::get("/".to_string(), 10, 0),
+///   Request::Get { url: "/".to_string(), timeout: 10, retries: 0 },
+/// );
+/// let mut request = Request::get_default();
+/// if let Request::Get { timeout, .. } = &mut request {
+///   *timeout = 10;
+/// }
+/// assert_eq!(request, Request::Get { url: String::new(), timeout: 10, retries: 0 });
+/// assert_eq!(Request::cancel(), Request::Cancel);
+/// ```
+///
+/// Constructors for variants whose names are keywords are raw identifiers,
+/// e.g. `Token::r#type(..)` for a variant `Type`. Variants whose
+/// constructors would have the same name are rejected:
+///
+/// ```compile_fail
+/// use unsafe_get::Constructors;
+///
+/// #[derive(Constructors)]
+/// enum Value {
+///   Foo(i32),
+///   // `foo_default` is already generated for `Foo`
+///   FooDefault,
+/// }
+/// ```
+#[proc_macro_derive(Constructors)]
+pub fn derive_constructors(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    constructors::derive(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// `#[derive(RedactedDebug)]` implements
+/// [`RedactedDebug`](trait.RedactedDebug.html), which the macros use
+/// instead of `Debug` to describe values in panic messages. It formats
+/// values like `#[derive(Debug)]`, but fields marked with `#[redact]` are
+/// formatted as `<redacted>`:
+///
+/// ```
+/// use unsafe_get::{get_result, RedactedDebug};
+///
+/// #[derive(Debug, RedactedDebug)]
+/// enum Login {
+///   Password {
+///     user: String,
+///     #[redact]
+///     password: String,
+///   },
+///   Token { token: String },
+/// }
+///
+/// let login = Login::Password { user: "alice".to_string(), password: "hunter2".to_string() };
+/// let error = get_result!(login, Login::Token, token).unwrap_err();
+/// assert!(error.value.contains("password: <redacted>"));
+/// assert!(!error.value.contains("hunter2"));
+/// ```
+#[proc_macro_derive(RedactedDebug, attributes(redact))]
+pub fn derive_redacted_debug(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    redacted_debug::derive(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// `#[derive(FieldList)]` implements [`FieldList`](trait.FieldList.html),
+/// which lists the names and types of the fields of the constructor a value
+/// is constructed with:
+///
+/// ```
+/// use unsafe_get::FieldList;
+///
+/// #[derive(FieldList)]
+/// enum Shape {
+///   Circle { radius: f64 },
+///   Rectangle(f64, f64),
+///   Empty,
+/// }
+///
+/// assert_eq!(Shape::Circle { radius: 1.0 }.field_list(), [("radius", "f64")]);
+/// assert_eq!(Shape::Rectangle(1.0, 2.0).field_list(), [("0", "f64"), ("1", "f64")]);
+/// assert_eq!(Shape::Empty.field_list(), []);
+/// ```
+///
+/// `get!` uses it to list the fields of the constructor it found when it
+/// panics.
+#[proc_macro_derive(FieldList)]
+pub fn derive_field_list(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    field_list::derive(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
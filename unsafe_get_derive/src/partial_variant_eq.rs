@@ -0,0 +1,73 @@
+use crate::variants::enum_data;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{parse_quote, DeriveInput, Field};
+
+pub fn derive(input: DeriveInput) -> syn::Result<TokenStream> {
+    let data = enum_data(&input, "PartialVariantEq")?;
+    let name = &input.ident;
+    let mut generics = input.generics.clone();
+    let type_params: Vec<_> = generics
+        .type_params()
+        .map(|param| param.ident.clone())
+        .collect();
+    for param in type_params {
+        generics
+            .make_where_clause()
+            .predicates
+            .push(parse_quote!(#param: ::core::cmp::PartialEq));
+    }
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+    let mut arms = Vec::new();
+    for variant in &data.variants {
+        let ident = &variant.ident;
+        let mut members = Vec::new();
+        let mut left = Vec::new();
+        let mut right = Vec::new();
+        for (index, (field, member)) in variant
+            .fields
+            .iter()
+            .zip(variant.fields.members())
+            .enumerate()
+        {
+            if !is_skipped(field)? {
+                members.push(member);
+                left.push(format_ident!("left_{}", index));
+                right.push(format_ident!("right_{}", index));
+            }
+        }
+        arms.push(quote! {
+            (Self::#ident { #(#members: #left,)* .. }, Self::#ident { #(#members: #right,)* .. }) => {
+                true #(&& #left == #right)*
+            }
+        });
+    }
+    Ok(quote! {
+        impl #impl_generics ::core::cmp::PartialEq for #name #type_generics #where_clause {
+            fn eq(&self, other: &Self) -> bool {
+                match (self, other) {
+                    #(#arms)*
+                    #[allow(unreachable_patterns)]
+                    _ => false,
+                }
+            }
+        }
+    })
+}
+
+fn is_skipped(field: &Field) -> syn::Result<bool> {
+    let mut skipped = false;
+    for attribute in &field.attrs {
+        if attribute.path().is_ident("variant_eq") {
+            attribute.parse_nested_meta(|meta| {
+                if meta.path.is_ident("skip") {
+                    skipped = true;
+                    Ok(())
+                } else {
+                    Err(meta.error("expected `skip`"))
+                }
+            })?;
+        }
+    }
+    Ok(skipped)
+}
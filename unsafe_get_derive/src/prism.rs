@@ -0,0 +1,93 @@
+use crate::variants::{enum_data, snake_case, VariantFields};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::DeriveInput;
+
+pub fn derive(input: DeriveInput) -> syn::Result<TokenStream> {
+    let data = enum_data(&input, "Prism")?;
+    let name = &input.ident;
+    let vis = &input.vis;
+    let generics = &input.generics;
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+    let mut items = Vec::new();
+    let mut methods = Vec::new();
+    for variant in &data.variants {
+        if variant.fields.len() != 1 {
+            continue;
+        }
+        let fields = VariantFields::new(variant);
+        let variant_ident = &variant.ident;
+        let focus = fields.types[0];
+        let member = &fields.members[0];
+        let pattern = fields.qualified_pattern(quote!(#name));
+        let binding = &fields.bindings[0];
+        let struct_name = format_ident!("{}{}Prism", name, variant_ident);
+        let struct_doc = format!(
+            "A [`Prism`](::unsafe_get::Prism) focusing on the field of `{}::{}`.",
+            name, variant_ident
+        );
+        items.push(quote! {
+            #[doc = #struct_doc]
+            #vis struct #struct_name #generics (::core::marker::PhantomData<fn() -> #name #type_generics>) #where_clause;
+
+            impl #impl_generics ::core::clone::Clone for #struct_name #type_generics #where_clause {
+                fn clone(&self) -> Self {
+                    *self
+                }
+            }
+
+            impl #impl_generics ::core::marker::Copy for #struct_name #type_generics #where_clause {}
+
+            impl #impl_generics ::unsafe_get::Prism for #struct_name #type_generics #where_clause {
+                type Source = #name #type_generics;
+                type Focus = #focus;
+
+                fn preview<'source>(&self, source: &'source Self::Source) -> ::core::option::Option<&'source Self::Focus> {
+                    match source {
+                        #pattern => ::core::option::Option::Some(#binding),
+                        #[allow(unreachable_patterns)]
+                        _ => ::core::option::Option::None,
+                    }
+                }
+
+                fn preview_mut<'source>(&self, source: &'source mut Self::Source) -> ::core::option::Option<&'source mut Self::Focus> {
+                    match source {
+                        #pattern => ::core::option::Option::Some(#binding),
+                        #[allow(unreachable_patterns)]
+                        _ => ::core::option::Option::None,
+                    }
+                }
+
+                fn extract(&self, source: Self::Source) -> ::core::result::Result<Self::Focus, Self::Source> {
+                    match source {
+                        #pattern => ::core::result::Result::Ok(#binding),
+                        #[allow(unreachable_patterns)]
+                        source => ::core::result::Result::Err(source),
+                    }
+                }
+
+                fn review(&self, focus: Self::Focus) -> Self::Source {
+                    #name::#variant_ident { #member: focus }
+                }
+            }
+        });
+        let method = format_ident!("{}_prism", snake_case(variant_ident));
+        let method_doc = format!(
+            "Returns a [`Prism`](::unsafe_get::Prism) focusing on the field of `{}::{}`.",
+            name, variant_ident
+        );
+        methods.push(quote! {
+            #[doc = #method_doc]
+            #vis fn #method() -> #struct_name #type_generics {
+                #struct_name(::core::marker::PhantomData)
+            }
+        });
+    }
+    Ok(quote! {
+        #(#items)*
+
+        impl #impl_generics #name #type_generics #where_clause {
+            #(#methods)*
+        }
+    })
+}
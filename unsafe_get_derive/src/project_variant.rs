@@ -0,0 +1,47 @@
+use crate::variants::snake_case;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::parse::{Parse, ParseStream};
+use syn::{Expr, Path, Token};
+
+/// The arguments of `project_variant!`: a reference to the value and the
+/// constructor it's known to be constructed with.
+pub struct Arguments {
+    value: Expr,
+    constructor: Path,
+}
+
+impl Parse for Arguments {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let value = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let constructor = input.parse()?;
+        Ok(Arguments { value, constructor })
+    }
+}
+
+/// Calls the `as_*_ref` method that `#[derive(VariantRefs)]` generates for
+/// the constructor.
+pub fn expand(arguments: Arguments) -> syn::Result<TokenStream> {
+    let Arguments { value, constructor } = arguments;
+    let variant = match constructor.segments.last() {
+        Some(segment) if constructor.segments.len() >= 2 => &segment.ident,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &constructor,
+                "project! expects a constructor of the form `Enum::Variant`",
+            ))
+        }
+    };
+    let method = format_ident!(
+        "as_{}",
+        snake_case(&format_ident!("{}Ref", variant)),
+        span = variant.span()
+    );
+    Ok(quote! {
+        match #value.#method() {
+            ::core::option::Option::Some(projection) => projection,
+            ::core::option::Option::None => ::core::unreachable!(),
+        }
+    })
+}
@@ -0,0 +1,79 @@
+use crate::variants::enum_data;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{parse_quote, DeriveInput, Field, Fields};
+
+pub fn derive(input: DeriveInput) -> syn::Result<TokenStream> {
+    let data = enum_data(&input, "RedactedDebug")?;
+    let name = &input.ident;
+    let mut generics = input.generics.clone();
+    let type_params: Vec<_> = generics
+        .type_params()
+        .map(|param| param.ident.clone())
+        .collect();
+    for param in type_params {
+        generics
+            .make_where_clause()
+            .predicates
+            .push(parse_quote!(#param: ::core::fmt::Debug));
+    }
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+    let mut arms = Vec::new();
+    for variant in &data.variants {
+        let ident = &variant.ident;
+        let variant_name = ident.to_string();
+        let mut members = Vec::new();
+        let mut bindings = Vec::new();
+        let mut calls = Vec::new();
+        for (index, (field, member)) in variant
+            .fields
+            .iter()
+            .zip(variant.fields.members())
+            .enumerate()
+        {
+            let value = if is_redacted(field)? {
+                quote!(&::core::format_args!("<redacted>"))
+            } else {
+                let binding = format_ident!("field_{}", index);
+                members.push(member);
+                bindings.push(binding.clone());
+                quote!(#binding)
+            };
+            calls.push(match &field.ident {
+                Some(field_ident) => {
+                    let field_name = field_ident.to_string();
+                    quote!(.field(#field_name, #value))
+                }
+                None => quote!(.field(#value)),
+            });
+        }
+        let body = match variant.fields {
+            Fields::Named(_) => quote!(formatter.debug_struct(#variant_name) #(#calls)* .finish()),
+            Fields::Unnamed(_) => quote!(formatter.debug_tuple(#variant_name) #(#calls)* .finish()),
+            Fields::Unit => quote!(formatter.write_str(#variant_name)),
+        };
+        arms.push(quote! {
+            Self::#ident { #(#members: #bindings,)* .. } => #body,
+        });
+    }
+    Ok(quote! {
+        impl #impl_generics ::unsafe_get::RedactedDebug for #name #type_generics #where_clause {
+            fn fmt_redacted(&self, formatter: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                match self {
+                    #(#arms)*
+                }
+            }
+        }
+    })
+}
+
+fn is_redacted(field: &Field) -> syn::Result<bool> {
+    let mut redacted = false;
+    for attribute in &field.attrs {
+        if attribute.path().is_ident("redact") {
+            attribute.meta.require_path_only()?;
+            redacted = true;
+        }
+    }
+    Ok(redacted)
+}
@@ -0,0 +1,50 @@
+use proc_macro2::{Delimiter, LineColumn, Span, TokenStream, TokenTree};
+
+/// Renders tokens as they were written, with whitespace collapsed into
+/// single spaces. `stringify!` can't be used for that, because tokens that
+/// went through a procedural macro lose the information about where the
+/// source had whitespace, e.g. `Enum::Foo` would be rendered as
+/// `Enum :: Foo`.
+pub fn render(tokens: &TokenStream) -> String {
+    let mut text = String::new();
+    render_into(tokens.clone(), &mut text, &mut None);
+    text
+}
+
+fn render_into(tokens: TokenStream, text: &mut String, previous_end: &mut Option<LineColumn>) {
+    for token in tokens {
+        match token {
+            TokenTree::Group(group) => {
+                let (open, close) = match group.delimiter() {
+                    Delimiter::Parenthesis => ("(", ")"),
+                    Delimiter::Brace => ("{", "}"),
+                    Delimiter::Bracket => ("[", "]"),
+                    Delimiter::None => {
+                        render_into(group.stream(), text, previous_end);
+                        continue;
+                    }
+                };
+                push_token(text, previous_end, group.span_open(), open);
+                render_into(group.stream(), text, previous_end);
+                push_token(text, previous_end, group.span_close(), close);
+            }
+            TokenTree::Punct(punct) if punct.as_char() == '\'' => {
+                // The span of the apostrophe of a lifetime can cover the
+                // whole lifetime, so the name is attached explicitly.
+                push_token(text, previous_end, punct.span(), "'");
+                *previous_end = None;
+            }
+            token => push_token(text, previous_end, token.span(), &token.to_string()),
+        }
+    }
+}
+
+fn push_token(text: &mut String, previous_end: &mut Option<LineColumn>, span: Span, token: &str) {
+    if let Some(previous_end) = previous_end {
+        if *previous_end != span.start() {
+            text.push(' ');
+        }
+    }
+    text.push_str(token);
+    *previous_end = Some(span.end());
+}
@@ -0,0 +1,67 @@
+use crate::variants::{enum_data, VariantFields};
+use proc_macro2::TokenStream;
+use quote::quote;
+use std::collections::BTreeMap;
+use syn::{DeriveInput, Fields, Variant};
+
+pub fn derive(input: DeriveInput) -> syn::Result<TokenStream> {
+    let data = enum_data(&input, "TryIntoVariant")?;
+    let name = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    let mut variants = Vec::new();
+    for variant in &data.variants {
+        if !is_skipped(variant)? && !matches!(variant.fields, Fields::Unit) {
+            variants.push(VariantFields::new(variant));
+        }
+    }
+    let mut payload_types = BTreeMap::new();
+    for fields in &variants {
+        let payload_type = fields.values_type(|ty| quote!(#ty));
+        if let Some(other) = payload_types.insert(payload_type.to_string(), &fields.variant.ident) {
+            return Err(syn::Error::new_spanned(
+                &fields.variant.ident,
+                format!(
+                    "variants {} and {} have the same payload type, skip one of them with #[try_into_variant(skip)]",
+                    other, fields.variant.ident
+                ),
+            ));
+        }
+    }
+    let impls = variants.iter().map(|fields| {
+        let pattern = fields.qualified_pattern(quote!(#name));
+        let values = fields.values();
+        let payload_type = fields.values_type(|ty| quote!(#ty));
+        let constructor = format!("{}::{}", name, fields.variant.ident);
+        quote! {
+            impl #impl_generics ::core::convert::TryFrom<#name #type_generics> for #payload_type #where_clause {
+                type Error = ::unsafe_get::UnexpectedVariant;
+
+                fn try_from(value: #name #type_generics) -> ::core::result::Result<Self, Self::Error> {
+                    match value {
+                        #pattern => ::core::result::Result::Ok(#values),
+                        #[allow(unreachable_patterns)]
+                        value => ::core::result::Result::Err(::unsafe_get::get_result!(@error #constructor, value)),
+                    }
+                }
+            }
+        }
+    });
+    Ok(quote!(#(#impls)*))
+}
+
+fn is_skipped(variant: &Variant) -> syn::Result<bool> {
+    let mut skipped = false;
+    for attribute in &variant.attrs {
+        if attribute.path().is_ident("try_into_variant") {
+            attribute.parse_nested_meta(|meta| {
+                if meta.path.is_ident("skip") {
+                    skipped = true;
+                    Ok(())
+                } else {
+                    Err(meta.error("expected `skip`"))
+                }
+            })?;
+        }
+    }
+    Ok(skipped)
+}
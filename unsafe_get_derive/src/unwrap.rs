@@ -0,0 +1,66 @@
+use crate::variants::{enum_data, snake_case, VariantFields};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::DeriveInput;
+
+pub fn derive(input: DeriveInput) -> syn::Result<TokenStream> {
+    let data = enum_data(&input, "Unwrap")?;
+    let name = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    let methods = data.variants.iter().map(|variant| {
+        let fields = VariantFields::new(variant);
+        let snake_case = snake_case(&variant.ident);
+        let pattern = fields.pattern();
+        let values = fields.values();
+        let constructor = format!("{}::{}", name, variant.ident);
+        let others = data
+            .variants
+            .iter()
+            .filter(|other| other.ident != variant.ident)
+            .map(|other| &other.ident);
+        let others: Vec<_> = others.collect();
+        let other_names: Vec<_> = others.iter().map(|other| other.to_string()).collect();
+        let method = |suffix: &str, receiver: TokenStream, wrap: TokenStream| {
+            let method = format_ident!("unwrap_{}{}", snake_case, suffix);
+            let values_type = fields.values_type(|ty| quote!(#wrap #ty));
+            let doc = format!(
+                "Returns the fields of `self`. Panics if it's not constructed with `{}`.",
+                constructor
+            );
+            let other_names = &other_names;
+            let messages = others.iter().map(|other| {
+                format!(
+                    "called `{}::{}()` on a `{}::{}` value",
+                    name, method, name, other
+                )
+            });
+            quote! {
+                #[doc = #doc]
+                #[track_caller]
+                pub fn #method(#receiver) -> #values_type {
+                    match self {
+                        #pattern => #values,
+                        #(value @ Self::#others { .. } => ::unsafe_get::__private::report_caller(
+                            ::unsafe_get::__private::Failure {
+                                expected: #constructor,
+                                actual: ::core::option::Option::Some(::std::string::String::from(#other_names)),
+                                value: ::unsafe_get::get!(@actual value),
+                                description: ::std::string::String::from(#messages),
+                            },
+                            "self",
+                        ),)*
+                    }
+                }
+            }
+        };
+        let owned = method("", quote!(self), quote!());
+        let shared = method("_ref", quote!(&self), quote!(&));
+        let mutable = method("_mut", quote!(&mut self), quote!(&mut));
+        quote!(#owned #shared #mutable)
+    });
+    Ok(quote! {
+        impl #impl_generics #name #type_generics #where_clause {
+            #(#methods)*
+        }
+    })
+}
@@ -0,0 +1,68 @@
+use crate::variants::{enum_data, snake_case, VariantFields};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::DeriveInput;
+
+pub fn derive(input: DeriveInput) -> syn::Result<TokenStream> {
+    let data = enum_data(&input, "VariantAccess")?;
+    let name = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    let methods = data.variants.iter().map(|variant| {
+        let fields = VariantFields::new(variant);
+        let snake_case = snake_case(&variant.ident);
+        let is = format_ident!("is_{}", snake_case);
+        let as_ = format_ident!("as_{}", snake_case);
+        let into = format_ident!("into_{}", snake_case);
+        let pattern = fields.pattern();
+        let values = fields.values();
+        let ref_type = fields.values_type(|ty| quote!(&#ty));
+        let owned_type = fields.values_type(|ty| quote!(#ty));
+        let constructor = format!("`{}::{}`", name, variant.ident);
+        let is_doc = format!(
+            "Returns `true` if `self` is constructed with {}.",
+            constructor
+        );
+        let as_doc = format!(
+            "Returns references to the fields of `self` if it's constructed with {}.",
+            constructor
+        );
+        let into_doc = format!(
+            "Returns the fields of `self` if it's constructed with {}.",
+            constructor
+        );
+        quote! {
+            #[doc = #is_doc]
+            #[allow(unused_variables)]
+            pub fn #is(&self) -> bool {
+                match self {
+                    #pattern => true,
+                    #[allow(unreachable_patterns)]
+                    _ => false,
+                }
+            }
+
+            #[doc = #as_doc]
+            pub fn #as_(&self) -> ::core::option::Option<#ref_type> {
+                match self {
+                    #pattern => ::core::option::Option::Some(#values),
+                    #[allow(unreachable_patterns)]
+                    _ => ::core::option::Option::None,
+                }
+            }
+
+            #[doc = #into_doc]
+            pub fn #into(self) -> ::core::option::Option<#owned_type> {
+                match self {
+                    #pattern => ::core::option::Option::Some(#values),
+                    #[allow(unreachable_patterns)]
+                    _ => ::core::option::Option::None,
+                }
+            }
+        }
+    });
+    Ok(quote! {
+        impl #impl_generics #name #type_generics #where_clause {
+            #(#methods)*
+        }
+    })
+}
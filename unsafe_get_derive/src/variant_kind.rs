@@ -0,0 +1,31 @@
+use crate::variants::enum_data;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::DeriveInput;
+
+pub fn derive(input: DeriveInput) -> syn::Result<TokenStream> {
+    let data = enum_data(&input, "VariantKind")?;
+    let name = &input.ident;
+    let vis = &input.vis;
+    let kind = format_ident!("{}Kind", name);
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    let variants: Vec<_> = data.variants.iter().map(|variant| &variant.ident).collect();
+    let kind_doc = format!("The constructors of `{}`, without their fields.", name);
+    let method_doc = format!("Returns the `{}` of `self`.", kind);
+    Ok(quote! {
+        #[doc = #kind_doc]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        #vis enum #kind {
+            #(#variants,)*
+        }
+
+        impl #impl_generics #name #type_generics #where_clause {
+            #[doc = #method_doc]
+            #vis fn kind(&self) -> #kind {
+                match *self {
+                    #(Self::#variants { .. } => #kind::#variants,)*
+                }
+            }
+        }
+    })
+}
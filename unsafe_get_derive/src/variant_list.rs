@@ -0,0 +1,50 @@
+use crate::variants::enum_data;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{DeriveInput, Fields};
+
+pub fn derive(input: DeriveInput) -> syn::Result<TokenStream> {
+    let data = enum_data(&input, "VariantList")?;
+    let name = &input.ident;
+    let vis = &input.vis;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    let variants: Vec<_> = data.variants.iter().map(|variant| &variant.ident).collect();
+    let count = variants.len();
+    let names = variants.iter().map(|variant| variant.to_string());
+    let count_doc = format!("The number of constructors of `{}`.", name);
+    let names_doc = format!(
+        "Returns the names of the constructors of `{}`, in declaration order.",
+        name
+    );
+    let iter = if data
+        .variants
+        .iter()
+        .all(|variant| matches!(variant.fields, Fields::Unit))
+    {
+        let iter_doc = format!(
+            "Returns an iterator over all values of `{}`, in declaration order.",
+            name
+        );
+        quote! {
+            #[doc = #iter_doc]
+            #vis fn iter_variants() -> impl ::core::iter::Iterator<Item = Self> {
+                ::core::iter::IntoIterator::into_iter([#(Self::#variants),*])
+            }
+        }
+    } else {
+        quote!()
+    };
+    Ok(quote! {
+        impl #impl_generics #name #type_generics #where_clause {
+            #[doc = #count_doc]
+            #vis const VARIANT_COUNT: usize = #count;
+
+            #[doc = #names_doc]
+            #vis fn variant_names() -> &'static [&'static str] {
+                &[#(#names),*]
+            }
+
+            #iter
+        }
+    })
+}
@@ -0,0 +1,24 @@
+use crate::variants::enum_data;
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::DeriveInput;
+
+pub fn derive(input: DeriveInput) -> syn::Result<TokenStream> {
+    let data = enum_data(&input, "VariantName")?;
+    let name = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    let arms = data.variants.iter().map(|variant| {
+        let ident = &variant.ident;
+        let variant_name = ident.to_string();
+        quote!(Self::#ident { .. } => #variant_name,)
+    });
+    Ok(quote! {
+        impl #impl_generics ::unsafe_get::VariantName for #name #type_generics #where_clause {
+            fn variant_name(&self) -> &'static str {
+                match *self {
+                    #(#arms)*
+                }
+            }
+        }
+    })
+}
@@ -0,0 +1,69 @@
+use crate::variants::{derive_attribute, enum_data, snake_case, used_generics, VariantFields};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{parse_quote, DeriveInput, Fields, GenericParam};
+
+pub fn derive(input: DeriveInput) -> syn::Result<TokenStream> {
+    let data = enum_data(&input, "VariantRefs")?;
+    let derives = derive_attribute(&input, "variant_refs")?;
+    let name = &input.ident;
+    let vis = &input.vis;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    let mut items = Vec::new();
+    let mut methods = Vec::new();
+    for variant in &data.variants {
+        if let Fields::Unit = variant.fields {
+            continue;
+        }
+        let fields = VariantFields::new(variant);
+        let mut generics = used_generics(&input.generics, &fields.types);
+        generics
+            .params
+            .insert(0, GenericParam::Lifetime(parse_quote!('variant)));
+        let (_, struct_type_generics, struct_where_clause) = generics.split_for_impl();
+        let members = &fields.members;
+        let bindings = &fields.bindings;
+        let types = &fields.types;
+        let variant_ident = &variant.ident;
+        let pattern = fields.pattern();
+        for (suffix, reference) in [("Ref", quote!(&'variant)), ("Mut", quote!(&'variant mut))] {
+            let struct_name = format_ident!("{}{}", variant_ident, suffix);
+            let method = format_ident!("as_{}", snake_case(&struct_name));
+            let struct_doc = format!("References to the fields of `{}::{}`.", name, variant_ident);
+            let body = match variant.fields {
+                Fields::Named(_) => quote!({ #(#vis #members: #reference #types,)* }),
+                _ => quote!((#(#vis #reference #types),*);),
+            };
+            items.push(quote! {
+                #[doc = #struct_doc]
+                #derives
+                #vis struct #struct_name #generics #struct_where_clause #body
+            });
+            let method_doc = format!(
+                "Returns references to the fields of `self` if it's constructed with `{}::{}`.",
+                name, variant_ident
+            );
+            let receiver = match suffix {
+                "Ref" => quote!(&'variant self),
+                _ => quote!(&'variant mut self),
+            };
+            methods.push(quote! {
+                #[doc = #method_doc]
+                #vis fn #method<'variant>(#receiver) -> ::core::option::Option<#struct_name #struct_type_generics> {
+                    match self {
+                        #pattern => ::core::option::Option::Some(#struct_name { #(#members: #bindings),* }),
+                        #[allow(unreachable_patterns)]
+                        _ => ::core::option::Option::None,
+                    }
+                }
+            });
+        }
+    }
+    Ok(quote! {
+        #(#items)*
+
+        impl #impl_generics #name #type_generics #where_clause {
+            #(#methods)*
+        }
+    })
+}
@@ -0,0 +1,95 @@
+use crate::variants::{derive_attribute, enum_data, snake_case, used_generics, VariantFields};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{DeriveInput, Fields, Ident};
+
+pub fn derive(input: DeriveInput) -> syn::Result<TokenStream> {
+    let data = enum_data(&input, "VariantStructs")?;
+    let derives = derive_attribute(&input, "variant_structs")?;
+    let name = &input.ident;
+    let vis = &input.vis;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    let mut items = Vec::new();
+    let mut methods = Vec::new();
+    for variant in &data.variants {
+        if !matches!(variant.fields, Fields::Named(_)) {
+            continue;
+        }
+        let fields = VariantFields::new(variant);
+        let struct_name = struct_name(variant);
+        let generics = used_generics(&input.generics, &fields.types);
+        let (struct_generics, struct_type_generics, struct_where_clause) =
+            generics.split_for_impl();
+        let members = &fields.members;
+        let bindings = &fields.bindings;
+        let types = &fields.types;
+        let variant_ident = &variant.ident;
+        let struct_doc = format!("The fields of `{}::{}`.", name, variant_ident);
+        let qualified_pattern = fields.qualified_pattern(quote!(#name));
+        let constructor = format!("{}::{}", name, variant_ident);
+        items.push(quote! {
+            #[doc = #struct_doc]
+            #derives
+            #vis struct #struct_name #struct_generics #struct_where_clause {
+                #(#vis #members: #types,)*
+            }
+
+            impl #impl_generics ::core::convert::From<#struct_name #struct_type_generics> for #name #type_generics #where_clause {
+                fn from(fields: #struct_name #struct_type_generics) -> Self {
+                    let #struct_name { #(#members: #bindings),* } = fields;
+                    Self::#variant_ident { #(#members: #bindings),* }
+                }
+            }
+
+            impl #impl_generics ::core::convert::TryFrom<#name #type_generics> for #struct_name #struct_type_generics #where_clause {
+                type Error = ::unsafe_get::UnexpectedVariant;
+
+                fn try_from(value: #name #type_generics) -> ::core::result::Result<Self, Self::Error> {
+                    match value {
+                        #qualified_pattern => ::core::result::Result::Ok(#struct_name { #(#members: #bindings),* }),
+                        #[allow(unreachable_patterns)]
+                        value => ::core::result::Result::Err(::unsafe_get::get_result!(@error #constructor, value)),
+                    }
+                }
+            }
+        });
+        let from_fields = format_ident!("from_{}", snake_case(&struct_name));
+        let into_fields = format_ident!("into_{}", snake_case(&struct_name));
+        let pattern = fields.pattern();
+        let into_fields_doc = format!(
+            "Returns the fields of `self` as a `{}` if it's constructed with `{}::{}`.",
+            struct_name, name, variant_ident
+        );
+        let from_fields_doc = format!(
+            "Constructs a `{}::{}` from a `{}`.",
+            name, variant_ident, struct_name
+        );
+        methods.push(quote! {
+            #[doc = #from_fields_doc]
+            #vis fn #from_fields(fields: #struct_name #struct_type_generics) -> Self {
+                let #struct_name { #(#members: #bindings),* } = fields;
+                Self::#variant_ident { #(#members: #bindings),* }
+            }
+
+            #[doc = #into_fields_doc]
+            #vis fn #into_fields(self) -> ::core::option::Option<#struct_name #struct_type_generics> {
+                match self {
+                    #pattern => ::core::option::Option::Some(#struct_name { #(#members: #bindings),* }),
+                    #[allow(unreachable_patterns)]
+                    _ => ::core::option::Option::None,
+                }
+            }
+        });
+    }
+    Ok(quote! {
+        #(#items)*
+
+        impl #impl_generics #name #type_generics #where_clause {
+            #(#methods)*
+        }
+    })
+}
+
+pub fn struct_name(variant: &syn::Variant) -> Ident {
+    format_ident!("{}Fields", variant.ident)
+}
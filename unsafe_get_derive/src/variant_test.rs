@@ -0,0 +1,86 @@
+use crate::render::render;
+use proc_macro2::{Delimiter, Group, TokenStream, TokenTree};
+use quote::{format_ident, quote};
+use syn::parse::Parser;
+use syn::punctuated::Punctuated;
+use syn::{Expr, ExprRange, Index, ItemFn, Path, ReturnType, Token};
+
+pub fn expand(expected: TokenStream, function: ItemFn) -> syn::Result<TokenStream> {
+    if let ReturnType::Default = function.sig.output {
+        return Err(syn::Error::new_spanned(
+            &function.sig,
+            "#[variant_test] expects a function that returns the value to check",
+        ));
+    }
+    let ItemFn {
+        attrs,
+        vis,
+        sig,
+        block,
+    } = function;
+    let name = &sig.ident;
+    let mut inner = sig.clone();
+    inner.ident = format_ident!("variant_test");
+    // The expected value is passed on unparsed, so that the panic messages
+    // contain it as it was written.
+    let tuple = match expected.clone().into_iter().last() {
+        Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Parenthesis => Some(group),
+        _ => None,
+    };
+    let has_fields = expected.clone().into_iter().any(
+        |token| matches!(token, TokenTree::Group(group) if group.delimiter() == Delimiter::Brace),
+    );
+    let assertion = if let Some(group) = tuple {
+        let mut constructor: Vec<TokenTree> = expected.into_iter().collect();
+        constructor.pop();
+        let constructor: TokenStream = constructor.into_iter().collect();
+        let display = render(&constructor);
+        let constructor: Path = syn::parse2(constructor)?;
+        let (rest, fields) = tuple_fields(&group)?;
+        quote!(::unsafe_get::assert_variant_eq!(
+            @assert "assert_variant_eq!", variant_test(), #constructor, #display, [#rest] #fields
+        ))
+    } else if has_fields {
+        quote!(::unsafe_get::assert_variant_eq!(variant_test(), #expected))
+    } else {
+        quote!(::unsafe_get::assert_variant!(variant_test(), #expected))
+    };
+    Ok(quote! {
+        #[test]
+        #(#attrs)*
+        #vis fn #name() {
+            #inner #block
+            #assertion
+        }
+    })
+}
+
+// Turns the fields of a tuple variant, e.g. `("x", ..)`, into the fields of
+// the equivalent braced pattern, `0: "x",`, and the rest pattern, `..`, in
+// the form that `assert_variant_eq!` uses internally.
+fn tuple_fields(group: &Group) -> syn::Result<(TokenStream, TokenStream)> {
+    let elements = Punctuated::<Expr, Token![,]>::parse_terminated.parse2(group.stream())?;
+    let count = elements.len();
+    let mut rest = TokenStream::new();
+    let mut fields = TokenStream::new();
+    for (position, element) in elements.into_iter().enumerate() {
+        if let Expr::Range(ExprRange {
+            start: None,
+            end: None,
+            ..
+        }) = element
+        {
+            if position + 1 != count {
+                return Err(syn::Error::new_spanned(
+                    element,
+                    "#[variant_test] only allows `..` as the last field",
+                ));
+            }
+            rest = quote!(..);
+        } else {
+            let index = Index::from(position);
+            fields.extend(quote!(#index: #element,));
+        }
+    }
+    Ok((rest, fields))
+}
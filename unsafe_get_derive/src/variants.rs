@@ -0,0 +1,171 @@
+use proc_macro2::{Span, TokenStream, TokenTree};
+use quote::{format_ident, quote, ToTokens};
+use std::collections::BTreeSet;
+use syn::{Data, DataEnum, DeriveInput, GenericParam, Generics, Ident, Member, Type, Variant};
+
+/// Returns the variants of the enum the derive is applied to, or an error
+/// if it's applied to something else.
+pub fn enum_data<'a>(input: &'a DeriveInput, derive: &str) -> syn::Result<&'a DataEnum> {
+    match &input.data {
+        Data::Enum(data) => Ok(data),
+        _ => Err(syn::Error::new(
+            Span::call_site(),
+            format!("{} can only be derived for enums", derive),
+        )),
+    }
+}
+
+/// Parses `#[<attribute>(derive(..))]` on the enum into a `#[derive(..)]`
+/// attribute for generated types.
+pub fn derive_attribute(input: &DeriveInput, attribute_name: &str) -> syn::Result<TokenStream> {
+    let mut derives = TokenStream::new();
+    for attribute in &input.attrs {
+        if attribute.path().is_ident(attribute_name) {
+            attribute.parse_nested_meta(|meta| {
+                if meta.path.is_ident("derive") {
+                    let content;
+                    syn::parenthesized!(content in meta.input);
+                    let traits: TokenStream = content.parse()?;
+                    derives = quote!(#[derive(#traits)]);
+                    Ok(())
+                } else {
+                    Err(meta.error("expected `derive(..)`"))
+                }
+            })?;
+        }
+    }
+    Ok(derives)
+}
+
+/// Converts a variant name like `NotFound` into `not_found`, for use in
+/// method names. Runs of capitals are treated as one word, so that acronyms
+/// stay together: `HTTPError` becomes `http_error`.
+pub fn snake_case(ident: &Ident) -> String {
+    let chars: Vec<char> = ident.to_string().chars().collect();
+    let mut result = String::new();
+    for (index, &char) in chars.iter().enumerate() {
+        if char.is_uppercase() && index > 0 {
+            let previous = chars[index - 1];
+            let next_is_lowercase = chars.get(index + 1).is_some_and(|next| next.is_lowercase());
+            if !previous.is_uppercase() && previous != '_'
+                || previous.is_uppercase() && next_is_lowercase
+            {
+                result.push('_');
+            }
+        }
+        result.extend(char.to_lowercase());
+    }
+    result
+}
+
+/// Returns the generic parameters of the enum that are used in the given
+/// types, for generating types that only contain some of the enum's fields.
+/// `where` predicates that mention removed parameters are removed as well.
+pub fn used_generics(generics: &Generics, types: &[&Type]) -> Generics {
+    let mut used = BTreeSet::new();
+    for ty in types {
+        collect_idents(ty.to_token_stream(), &mut used);
+    }
+    let param_ident = |param: &GenericParam| match param {
+        GenericParam::Type(param) => param.ident.to_string(),
+        GenericParam::Lifetime(param) => param.lifetime.ident.to_string(),
+        GenericParam::Const(param) => param.ident.to_string(),
+    };
+    let mut result = generics.clone();
+    let removed: BTreeSet<String> = generics
+        .params
+        .iter()
+        .map(param_ident)
+        .filter(|ident| !used.contains(ident))
+        .collect();
+    result.params = generics
+        .params
+        .iter()
+        .filter(|param| !removed.contains(&param_ident(param)))
+        .cloned()
+        .collect();
+    if let Some(where_clause) = &mut result.where_clause {
+        where_clause.predicates = where_clause
+            .predicates
+            .iter()
+            .filter(|predicate| {
+                let mut idents = BTreeSet::new();
+                collect_idents(predicate.to_token_stream(), &mut idents);
+                idents.is_disjoint(&removed)
+            })
+            .cloned()
+            .collect();
+    }
+    result
+}
+
+fn collect_idents(tokens: TokenStream, idents: &mut BTreeSet<String>) {
+    for token in tokens {
+        match token {
+            TokenTree::Ident(ident) => {
+                idents.insert(ident.to_string());
+            }
+            TokenTree::Group(group) => collect_idents(group.stream(), idents),
+            _ => {}
+        }
+    }
+}
+
+/// The fields of a variant, with the names they get bound to in patterns.
+/// Fields are bound to `field_0`, `field_1` and so on, so that the
+/// generated code doesn't trigger lints about the user's field names.
+pub struct VariantFields<'a> {
+    pub variant: &'a Variant,
+    pub members: Vec<Member>,
+    pub bindings: Vec<Ident>,
+    pub types: Vec<&'a Type>,
+}
+
+impl<'a> VariantFields<'a> {
+    pub fn new(variant: &'a Variant) -> Self {
+        let members = variant.fields.members().collect();
+        let bindings = (0..variant.fields.len())
+            .map(|index| format_ident!("field_{}", index))
+            .collect();
+        let types = variant.fields.iter().map(|field| &field.ty).collect();
+        VariantFields {
+            variant,
+            members,
+            bindings,
+            types,
+        }
+    }
+
+    /// A pattern matching the variant and binding all its fields, e.g.
+    /// `Self::Foo { 0: field_0, 1: field_1 }`.
+    pub fn pattern(&self) -> TokenStream {
+        self.qualified_pattern(quote!(Self))
+    }
+
+    /// Like `pattern`, but with the given path to the enum instead of `Self`.
+    pub fn qualified_pattern(&self, enum_path: TokenStream) -> TokenStream {
+        let ident = &self.variant.ident;
+        let members = &self.members;
+        let bindings = &self.bindings;
+        quote!(#enum_path::#ident { #(#members: #bindings),* })
+    }
+
+    /// The bound fields as a single expression: `()` for no fields, the
+    /// field itself for one field and a tuple for multiple fields.
+    pub fn values(&self) -> TokenStream {
+        tuple(&self.bindings)
+    }
+
+    /// The type of `values`, with every field type passed through `wrap`.
+    pub fn values_type(&self, wrap: impl Fn(&Type) -> TokenStream) -> TokenStream {
+        let types: Vec<TokenStream> = self.types.iter().map(|ty| wrap(ty)).collect();
+        tuple(&types)
+    }
+}
+
+fn tuple<T: quote::ToTokens>(elements: &[T]) -> TokenStream {
+    match elements {
+        [element] => quote!(#element),
+        elements => quote!((#(#elements),*)),
+    }
+}
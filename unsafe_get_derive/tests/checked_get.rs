@@ -0,0 +1,61 @@
+use unsafe_get::{checked_get, CheckedFields};
+
+#[derive(Debug, CheckedFields)]
+enum Enum {
+    Named { a: i32, b: String },
+    Pair(i32, i32),
+    Unit,
+}
+
+mod nested {
+    use unsafe_get::CheckedFields;
+
+    #[derive(Debug, CheckedFields)]
+    pub enum Nested {
+        Value(i32),
+    }
+}
+
+#[test]
+fn returns_fields() {
+    let value = Enum::Named {
+        a: 42,
+        b: "b".to_string(),
+    };
+    assert_eq!(checked_get!(&value, Enum::Named, a), &42);
+    assert_eq!(
+        checked_get!(&value, Enum::Named, (a, b)),
+        (&42, &"b".to_string())
+    );
+    assert_eq!(checked_get!(Enum::Pair(1, 2), Enum::Pair, 1), 2);
+    checked_get!(Enum::Unit, Enum::Unit);
+}
+
+#[test]
+fn supports_paths_to_other_modules() {
+    assert_eq!(
+        checked_get!(nested::Nested::Value(42), nested::Nested::Value, 0),
+        42
+    );
+}
+
+#[test]
+#[should_panic(
+    expected = "get!: expected enum constructor: Enum::Pair, got Unit while matching `Enum::Unit`: in case 1"
+)]
+fn passes_other_arguments_on_to_get() {
+    checked_get!(Enum::Unit, Enum::Pair, 0, "in case {}", 1);
+}
+
+#[derive(Debug, CheckedFields)]
+enum Generic<T> {
+    Value { value: T },
+}
+
+#[test]
+fn supports_generic_enums() {
+    assert_eq!(
+        checked_get!(Generic::Value { value: 42 }, Generic::Value, value),
+        42
+    );
+}
@@ -0,0 +1,71 @@
+use unsafe_get::Constructors;
+
+#[derive(Debug, PartialEq, Constructors)]
+enum Enum {
+    Named { a: i32, b: String },
+    Pair(i32, Option<bool>),
+    Unit,
+}
+
+#[derive(Debug, PartialEq)]
+struct NoDefault;
+
+#[derive(Debug, PartialEq, Constructors)]
+enum Generic<T> {
+    Value(T),
+    Other(NoDefault),
+}
+
+#[test]
+fn constructs_variants_from_fields() {
+    assert_eq!(
+        Enum::named(1, "b".to_string()),
+        Enum::Named {
+            a: 1,
+            b: "b".to_string()
+        }
+    );
+    assert_eq!(Enum::pair(1, Some(true)), Enum::Pair(1, Some(true)));
+    assert_eq!(Enum::unit(), Enum::Unit);
+}
+
+#[test]
+fn constructs_variants_with_default_fields() {
+    assert_eq!(
+        Enum::named_default(),
+        Enum::Named {
+            a: 0,
+            b: String::new()
+        }
+    );
+    assert_eq!(Enum::pair_default(), Enum::Pair(0, None));
+}
+
+#[test]
+fn works_for_generic_enums_and_fields_without_default() {
+    assert_eq!(Generic::<u8>::value_default(), Generic::Value(0));
+    assert_eq!(Generic::<u8>::other(NoDefault), Generic::Other(NoDefault));
+}
+
+#[derive(Debug, PartialEq, Constructors)]
+enum Token {
+    Type { name: String },
+    Match,
+}
+
+#[test]
+fn uses_raw_identifiers_for_keywords() {
+    assert_eq!(
+        Token::r#type("foo".to_string()),
+        Token::Type {
+            name: "foo".to_string()
+        }
+    );
+    assert_eq!(
+        Token::type_default(),
+        Token::Type {
+            name: String::new()
+        }
+    );
+    assert_eq!(Token::r#match(), Token::Match);
+}
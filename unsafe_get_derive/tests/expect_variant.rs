@@ -0,0 +1,59 @@
+use std::panic::catch_unwind;
+use unsafe_get::ExpectVariant;
+
+#[derive(Debug, ExpectVariant)]
+enum Enum {
+    Named { a: i32, b: String },
+    Single { value: bool },
+    Pair(i32, i32),
+    Unit,
+}
+
+#[allow(dead_code)]
+#[derive(ExpectVariant)]
+enum NoDebug {
+    A(i32),
+    B,
+}
+
+#[test]
+fn returns_fields() {
+    let value = Enum::Named {
+        a: 42,
+        b: "b".to_string(),
+    };
+    assert_eq!(value.expect_named(), (42, "b".to_string()));
+    assert!(Enum::Single { value: true }.expect_single());
+    assert_eq!(Enum::Pair(1, 2).expect_pair(), (1, 2));
+    Enum::Unit.expect_unit();
+}
+
+#[test]
+#[should_panic(expected = "Enum::expect_pair: expected enum constructor: Enum::Pair, got Unit")]
+fn panics_for_other_constructors() {
+    Enum::Unit.expect_pair();
+}
+
+#[test]
+fn prefixes_panic_messages_with_the_location_of_the_call() {
+    // The `color` feature appends a colored diff to the panic messages.
+    std::env::set_var("NO_COLOR", "1");
+    let line = line!() + 1;
+    let payload = catch_unwind(|| Enum::Unit.expect_pair()).unwrap_err();
+    assert_eq!(
+        payload.downcast_ref::<String>().unwrap(),
+        &format!(
+            "{}:{}:46: Enum::expect_pair: expected enum constructor: Enum::Pair, got Unit",
+            file!(),
+            line
+        )
+    );
+}
+
+#[test]
+#[should_panic(
+    expected = "NoDebug::expect_a: expected enum constructor: NoDebug::A, got a value of type expect_variant::NoDebug (which doesn't implement Debug)"
+)]
+fn works_for_enums_without_debug() {
+    NoDebug::B.expect_a();
+}
@@ -0,0 +1,52 @@
+use std::collections::HashMap;
+use unsafe_get::{get, FieldList};
+
+#[allow(dead_code)]
+#[derive(Debug, FieldList)]
+enum Enum<T> {
+    Foo {
+        foo: i32,
+    },
+    Bar {
+        bar: bool,
+        map: HashMap<String, Vec<T>>,
+    },
+    Pair(T, &'static str),
+    Unit,
+}
+
+#[test]
+fn lists_the_fields_of_the_variant() {
+    assert_eq!(Enum::<u8>::Foo { foo: 1 }.field_list(), [("foo", "i32")]);
+    assert_eq!(
+        Enum::<u8>::Bar {
+            bar: true,
+            map: HashMap::new()
+        }
+        .field_list(),
+        [("bar", "bool"), ("map", "HashMap<String, Vec<T>>")]
+    );
+    assert_eq!(
+        Enum::Pair(1, "one").field_list(),
+        [("0", "T"), ("1", "&'static str")]
+    );
+    assert_eq!(Enum::<u8>::Unit.field_list(), []);
+}
+
+#[test]
+#[should_panic(
+    expected = "actual variant `Bar` has fields: bar: bool, map: HashMap<String, Vec<T>> while matching"
+)]
+fn lists_the_fields_of_the_actual_variant_on_mismatches() {
+    let value: Enum<u8> = Enum::Bar {
+        bar: true,
+        map: HashMap::new(),
+    };
+    get!(value, Enum::Foo, foo);
+}
+
+#[test]
+#[should_panic(expected = "got Unit, actual variant `Unit` has no fields while matching")]
+fn says_when_the_actual_variant_has_no_fields() {
+    get!(Enum::<u8>::Unit, Enum::Pair, 0);
+}
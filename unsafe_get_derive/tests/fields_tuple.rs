@@ -0,0 +1,35 @@
+use unsafe_get::FieldsTuple;
+
+#[derive(Debug, FieldsTuple)]
+enum Enum {
+    Named { a: i32, b: String },
+    Single { value: bool },
+    Pair(i32, i32),
+    Unit,
+}
+
+#[test]
+fn returns_fields_as_tuples() {
+    let value = Enum::Named {
+        a: 42,
+        b: "b".to_string(),
+    };
+    assert_eq!(value.expect_named_tuple(), (42, "b".to_string()));
+    assert_eq!(Enum::Single { value: true }.expect_single_tuple(), (true,));
+    assert_eq!(Enum::Pair(1, 2).into_pair_tuple(), Some((1, 2)));
+    assert_eq!(Enum::Unit.into_unit_tuple(), Some(()));
+}
+
+#[test]
+fn into_returns_none_for_other_constructors() {
+    assert_eq!(Enum::Unit.into_pair_tuple(), None);
+    assert_eq!(Enum::Pair(1, 2).into_unit_tuple(), None);
+}
+
+#[test]
+#[should_panic(
+    expected = "Enum::expect_pair_tuple: expected enum constructor: Enum::Pair, got Single"
+)]
+fn expect_panics_for_other_constructors() {
+    Enum::Single { value: true }.expect_pair_tuple();
+}
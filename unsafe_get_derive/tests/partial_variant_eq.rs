@@ -0,0 +1,68 @@
+use unsafe_get::PartialVariantEq;
+
+#[allow(dead_code)]
+#[derive(Debug, PartialVariantEq)]
+enum Enum {
+    Named {
+        #[variant_eq(skip)]
+        id: u64,
+        name: String,
+    },
+    Pair(i32, #[variant_eq(skip)] i32),
+    Unit,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, PartialVariantEq)]
+enum Generic<T> {
+    Value(T, #[variant_eq(skip)] u64),
+}
+
+#[test]
+fn ignores_skipped_fields() {
+    assert_eq!(
+        Enum::Named {
+            id: 1,
+            name: "foo".to_string()
+        },
+        Enum::Named {
+            id: 2,
+            name: "foo".to_string()
+        }
+    );
+    assert_eq!(Enum::Pair(1, 2), Enum::Pair(1, 3));
+    assert_eq!(Enum::Unit, Enum::Unit);
+}
+
+#[test]
+fn compares_other_fields() {
+    assert_ne!(
+        Enum::Named {
+            id: 1,
+            name: "foo".to_string()
+        },
+        Enum::Named {
+            id: 1,
+            name: "bar".to_string()
+        }
+    );
+    assert_ne!(Enum::Pair(1, 2), Enum::Pair(3, 2));
+}
+
+#[test]
+fn compares_constructors() {
+    assert_ne!(Enum::Pair(1, 2), Enum::Unit);
+    assert_ne!(
+        Enum::Unit,
+        Enum::Named {
+            id: 1,
+            name: "foo".to_string()
+        }
+    );
+}
+
+#[test]
+fn works_for_generic_enums() {
+    assert_eq!(Generic::Value("foo", 1), Generic::Value("foo", 2));
+    assert_ne!(Generic::Value("foo", 1), Generic::Value("bar", 1));
+}
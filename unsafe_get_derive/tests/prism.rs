@@ -0,0 +1,98 @@
+use unsafe_get::Prism;
+
+#[derive(Debug, PartialEq, Prism)]
+enum Outer {
+    Inner(Inner),
+    Named { value: String },
+    Pair(i32, i32),
+    Unit,
+}
+
+#[derive(Debug, PartialEq, Prism)]
+enum Inner {
+    Number(i32),
+    Other,
+}
+
+#[derive(Debug, PartialEq, Prism)]
+enum Generic<'a, T> {
+    Value(&'a T),
+    Nothing,
+}
+
+#[test]
+fn preview_returns_the_field() {
+    let value = Outer::Named {
+        value: "foo".to_string(),
+    };
+    assert_eq!(
+        Outer::named_prism().preview(&value),
+        Some(&"foo".to_string())
+    );
+    assert_eq!(Outer::inner_prism().preview(&value), None);
+}
+
+#[test]
+fn preview_mut_allows_mutating_the_field() {
+    let mut value = Outer::Inner(Inner::Number(1));
+    *Outer::inner_prism().preview_mut(&mut value).unwrap() = Inner::Other;
+    assert_eq!(value, Outer::Inner(Inner::Other));
+    assert_eq!(Outer::named_prism().preview_mut(&mut value), None);
+}
+
+#[test]
+fn extract_returns_the_source_for_other_variants() {
+    assert_eq!(Inner::number_prism().extract(Inner::Number(42)), Ok(42));
+    assert_eq!(
+        Inner::number_prism().extract(Inner::Other),
+        Err(Inner::Other)
+    );
+}
+
+#[test]
+fn review_constructs_the_variant() {
+    assert_eq!(
+        Outer::named_prism().review("foo".to_string()),
+        Outer::Named {
+            value: "foo".to_string()
+        }
+    );
+}
+
+#[test]
+fn over_modifies_the_field() {
+    assert_eq!(
+        Inner::number_prism().over(Inner::Number(1), |number| number + 1),
+        Inner::Number(2)
+    );
+    assert_eq!(
+        Inner::number_prism().over(Inner::Other, |number| number + 1),
+        Inner::Other
+    );
+}
+
+#[test]
+fn composed_prisms_reach_through_nested_enums() {
+    let number = Outer::inner_prism().then(Inner::number_prism());
+    let value = number.review(1);
+    assert_eq!(value, Outer::Inner(Inner::Number(1)));
+    assert_eq!(number.preview(&value), Some(&1));
+    assert_eq!(number.preview(&Outer::Unit), None);
+    assert_eq!(
+        number.over(value, |number| number * 10),
+        Outer::Inner(Inner::Number(10))
+    );
+    assert_eq!(
+        number.extract(Outer::Inner(Inner::Other)),
+        Err(Outer::Inner(Inner::Other))
+    );
+    assert_eq!(number.extract(Outer::Pair(1, 2)), Err(Outer::Pair(1, 2)));
+}
+
+#[test]
+fn works_for_generic_enums() {
+    let value = 42;
+    let prism = Generic::value_prism();
+    assert_eq!(prism.preview(&Generic::Value(&value)), Some(&&42));
+    assert_eq!(prism.preview(&Generic::Nothing), None);
+}
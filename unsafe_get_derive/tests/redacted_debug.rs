@@ -0,0 +1,98 @@
+use std::error::Error;
+use std::fmt;
+use std::panic::catch_unwind;
+use unsafe_get::{expect_error_variant, get, RedactedDebug};
+
+#[allow(dead_code)]
+#[derive(Debug, RedactedDebug)]
+enum Credentials<T> {
+    Token {
+        user: T,
+        #[redact]
+        token: String,
+    },
+    Key(#[redact] Vec<u8>, u32),
+    Anonymous,
+}
+
+fn panic_message(credentials: Credentials<&'static str>) -> String {
+    let payload = catch_unwind(|| get!(credentials, Credentials::Anonymous)).unwrap_err();
+    payload.downcast_ref::<String>().unwrap().clone()
+}
+
+#[test]
+fn redacts_named_fields_in_panic_messages() {
+    let message = panic_message(Credentials::Token {
+        user: "alice",
+        token: "secret".to_string(),
+    });
+    assert!(message.contains("expected enum constructor: Credentials::Anonymous, got Token"));
+    assert!(message.contains("user: \"alice\""));
+    assert!(message.contains("token: <redacted>"));
+    assert!(!message.contains("secret"));
+}
+
+#[test]
+fn redacts_tuple_fields_in_panic_messages() {
+    let message = panic_message(Credentials::Key(vec![1, 2, 3], 42));
+    assert!(message.contains("got Key("));
+    assert!(message.contains("<redacted>"));
+    assert!(message.contains("42"));
+    assert!(!message.contains("[1, 2, 3]"));
+}
+
+#[allow(dead_code)]
+#[derive(Debug, RedactedDebug)]
+enum LoginError {
+    WrongPassword {
+        #[redact]
+        password: String,
+    },
+    Locked,
+}
+
+impl fmt::Display for LoginError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "login failed")
+    }
+}
+
+impl Error for LoginError {}
+
+#[test]
+fn redacts_fields_of_errors() {
+    let error = LoginError::WrongPassword {
+        password: "secret".to_string(),
+    };
+    let payload = catch_unwind(|| expect_error_variant!(error, LoginError::Locked)).unwrap_err();
+    let message = payload.downcast_ref::<String>().unwrap();
+    assert!(message.contains("got WrongPassword"));
+    assert!(message.contains("password: <redacted>"));
+    assert!(!message.contains("secret"));
+}
+
+#[test]
+fn leaves_debug_unchanged() {
+    let credentials = Credentials::Token {
+        user: "alice",
+        token: "secret".to_string(),
+    };
+    assert_eq!(
+        format!("{:?}", credentials),
+        "Token { user: \"alice\", token: \"secret\" }"
+    );
+}
+
+#[test]
+fn formats_unit_variants() {
+    struct Redacted<'a>(&'a Credentials<&'static str>);
+    impl std::fmt::Debug for Redacted<'_> {
+        fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+            self.0.fmt_redacted(formatter)
+        }
+    }
+    assert_eq!(
+        format!("{:?}", Redacted(&Credentials::Anonymous)),
+        "Anonymous"
+    );
+}
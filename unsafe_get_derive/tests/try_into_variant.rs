@@ -0,0 +1,80 @@
+use std::convert::{TryFrom, TryInto};
+use unsafe_get::{TryIntoVariant, UnexpectedVariant};
+
+#[allow(dead_code)]
+#[derive(Debug, TryIntoVariant)]
+enum Enum {
+    Named {
+        a: i32,
+        b: String,
+    },
+    Single {
+        value: bool,
+    },
+    Pair(i32, i32),
+    #[try_into_variant(skip)]
+    OtherPair(i32, i32),
+    Unit,
+}
+
+#[derive(Debug, TryIntoVariant)]
+enum Generic<T> {
+    Value(Vec<T>),
+    Nothing,
+}
+
+#[test]
+fn converts_into_payloads() {
+    let value = Enum::Named {
+        a: 42,
+        b: "b".to_string(),
+    };
+    assert_eq!(<(i32, String)>::try_from(value), Ok((42, "b".to_string())));
+    assert_eq!(bool::try_from(Enum::Single { value: true }), Ok(true));
+    let pair: Result<(i32, i32), _> = Enum::Pair(1, 2).try_into();
+    assert_eq!(pair, Ok((1, 2)));
+}
+
+#[test]
+fn returns_errors_for_other_constructors() {
+    let error = bool::try_from(Enum::Unit).unwrap_err();
+    assert_eq!(
+        (error.expected, error.actual.as_str()),
+        ("Enum::Single", "Unit")
+    );
+    let error = <(i32, i32)>::try_from(Enum::OtherPair(1, 2)).unwrap_err();
+    assert_eq!(
+        (error.expected, error.actual.as_str()),
+        ("Enum::Pair", "OtherPair")
+    );
+}
+
+#[test]
+fn supports_generic_enums() {
+    assert_eq!(Vec::try_from(Generic::Value(vec![1, 2])), Ok(vec![1, 2]));
+    assert!(Vec::<i32>::try_from(Generic::Nothing).is_err());
+}
+
+fn sum(value: Enum) -> Result<i32, UnexpectedVariant> {
+    let (a, b): (i32, i32) = value.try_into()?;
+    Ok(a + b)
+}
+
+#[test]
+fn composes_with_the_question_mark_operator() {
+    assert_eq!(sum(Enum::Pair(1, 2)), Ok(3));
+    assert!(sum(Enum::Unit).is_err());
+}
+
+#[derive(Debug, TryIntoVariant)]
+enum Message {
+    Foo(i32),
+    Bar { bar: bool },
+}
+
+#[test]
+fn reports_the_name_of_the_actual_variant() {
+    let error = i32::try_from(Message::Bar { bar: true }).unwrap_err();
+    assert_eq!(error.actual, "Bar");
+    assert_eq!(bool::try_from(Message::Foo(1)).unwrap_err().actual, "Foo");
+}
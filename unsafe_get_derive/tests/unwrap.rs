@@ -0,0 +1,48 @@
+use unsafe_get::Unwrap;
+
+#[derive(Debug, PartialEq, Unwrap)]
+enum Enum {
+    Named { a: i32, b: String },
+    Single { value: bool },
+    Pair(i32, i32),
+    Unit,
+    HTTPError(u16),
+}
+
+#[test]
+fn returns_fields() {
+    let value = Enum::Named {
+        a: 42,
+        b: "b".to_string(),
+    };
+    assert_eq!(value.unwrap_named_ref(), (&42, &"b".to_string()));
+    assert_eq!(value.unwrap_named(), (42, "b".to_string()));
+    assert!(Enum::Single { value: true }.unwrap_single());
+    Enum::Unit.unwrap_unit();
+}
+
+#[test]
+fn returns_mutable_references() {
+    let mut value = Enum::Pair(1, 2);
+    let (a, b) = value.unwrap_pair_mut();
+    std::mem::swap(a, b);
+    assert_eq!(value, Enum::Pair(2, 1));
+}
+
+#[test]
+fn keeps_acronyms_together_in_method_names() {
+    assert_eq!(Enum::HTTPError(404).unwrap_http_error(), 404);
+    assert_eq!(Enum::HTTPError(404).unwrap_http_error_ref(), &404);
+}
+
+#[test]
+#[should_panic(expected = "called `Enum::unwrap_pair()` on a `Enum::Unit` value")]
+fn panics_for_other_constructors() {
+    Enum::Unit.unwrap_pair();
+}
+
+#[test]
+#[should_panic(expected = "called `Enum::unwrap_unit_ref()` on a `Enum::Single` value")]
+fn panics_for_other_constructors_by_reference() {
+    Enum::Single { value: false }.unwrap_unit_ref();
+}
@@ -0,0 +1,57 @@
+use unsafe_get::VariantAccess;
+
+#[derive(Debug, PartialEq, VariantAccess)]
+enum Enum {
+    Foo { foo: i32, bar: String },
+    Single { value: bool },
+    Pair(i32, i32),
+    NotFound,
+    HTTPError(u16),
+}
+
+#[derive(VariantAccess)]
+enum Generic<'a, T: Clone> {
+    Borrowed(&'a T),
+    Owned(T),
+}
+
+#[test]
+fn generates_is_methods() {
+    assert!(Enum::Pair(1, 2).is_pair());
+    assert!(!Enum::Pair(1, 2).is_foo());
+    assert!(Enum::NotFound.is_not_found());
+}
+
+#[test]
+fn keeps_acronyms_together_in_method_names() {
+    assert!(Enum::HTTPError(404).is_http_error());
+    assert_eq!(Enum::HTTPError(404).as_http_error(), Some(&404));
+    assert_eq!(Enum::HTTPError(404).into_http_error(), Some(404));
+}
+
+#[test]
+fn generates_as_methods() {
+    let value = Enum::Foo {
+        foo: 42,
+        bar: "bar".to_string(),
+    };
+    assert_eq!(value.as_foo(), Some((&42, &"bar".to_string())));
+    assert_eq!(value.as_pair(), None);
+    assert_eq!(Enum::Single { value: true }.as_single(), Some(&true));
+    assert_eq!(Enum::NotFound.as_not_found(), Some(()));
+}
+
+#[test]
+fn generates_into_methods() {
+    assert_eq!(Enum::Pair(1, 2).into_pair(), Some((1, 2)));
+    assert_eq!(Enum::Single { value: true }.into_single(), Some(true));
+    assert_eq!(Enum::NotFound.into_foo(), None);
+}
+
+#[test]
+fn supports_generic_enums() {
+    let value = 42;
+    assert_eq!(Generic::Borrowed(&value).into_borrowed(), Some(&42));
+    assert_eq!(Generic::Owned(value).as_owned(), Some(&42));
+    assert!(!Generic::Owned(value).is_borrowed());
+}
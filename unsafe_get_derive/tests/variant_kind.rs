@@ -0,0 +1,38 @@
+use std::collections::BTreeMap;
+use unsafe_get::VariantKind;
+
+#[allow(dead_code)]
+#[derive(VariantKind)]
+enum Enum {
+    Named { a: i32 },
+    Pair(i32, i32),
+    Unit,
+}
+
+#[allow(dead_code)]
+#[derive(VariantKind)]
+enum Generic<T> {
+    Value(T),
+    Nothing,
+}
+
+#[test]
+fn returns_kinds() {
+    assert_eq!(Enum::Named { a: 1 }.kind(), EnumKind::Named);
+    assert_eq!(Enum::Pair(1, 2).kind(), EnumKind::Pair);
+    assert_eq!(Enum::Unit.kind(), EnumKind::Unit);
+    assert_eq!(Generic::Value(42).kind(), GenericKind::Value);
+}
+
+#[test]
+fn allows_grouping_by_kind() {
+    let values = vec![Enum::Pair(1, 2), Enum::Unit, Enum::Pair(3, 4)];
+    let mut counts = BTreeMap::new();
+    for value in &values {
+        *counts.entry(value.kind()).or_insert(0) += 1;
+    }
+    assert_eq!(
+        counts.into_iter().collect::<Vec<_>>(),
+        vec![(EnumKind::Pair, 2), (EnumKind::Unit, 1)]
+    );
+}
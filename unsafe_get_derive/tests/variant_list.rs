@@ -0,0 +1,41 @@
+use unsafe_get::VariantList;
+
+#[allow(dead_code)]
+#[derive(VariantList)]
+enum WithFields {
+    Named { a: i32 },
+    Pair(i32, i32),
+    Unit,
+}
+
+#[derive(Debug, PartialEq, VariantList)]
+enum Fieldless {
+    A,
+    B,
+    C,
+}
+
+#[allow(dead_code)]
+#[derive(VariantList)]
+enum Empty {}
+
+#[test]
+fn counts_variants() {
+    assert_eq!(WithFields::VARIANT_COUNT, 3);
+    assert_eq!(Fieldless::VARIANT_COUNT, 3);
+    assert_eq!(Empty::VARIANT_COUNT, 0);
+}
+
+#[test]
+fn lists_variant_names() {
+    assert_eq!(WithFields::variant_names(), ["Named", "Pair", "Unit"]);
+    assert!(Empty::variant_names().is_empty());
+}
+
+#[test]
+fn iterates_over_fieldless_enums() {
+    assert_eq!(
+        Fieldless::iter_variants().collect::<Vec<_>>(),
+        vec![Fieldless::A, Fieldless::B, Fieldless::C]
+    );
+}
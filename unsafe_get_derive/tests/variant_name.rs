@@ -0,0 +1,40 @@
+use unsafe_get::{assert_same_variant, get, variant_name, VariantName};
+
+#[allow(dead_code)]
+#[derive(VariantName)]
+enum Enum {
+    Named { a: i32 },
+    Pair(i32, i32),
+    Unit,
+}
+
+#[derive(Debug, VariantName)]
+enum WithDebug {
+    Custom,
+}
+
+#[test]
+fn returns_variant_names() {
+    assert_eq!(Enum::Named { a: 1 }.variant_name(), "Named");
+    assert_eq!(Enum::Pair(1, 2).variant_name(), "Pair");
+    assert_eq!(VariantName::variant_name(&Box::new(Enum::Unit)), "Unit");
+}
+
+#[test]
+fn is_used_by_the_variant_name_macro() {
+    assert_eq!(variant_name!(Enum::Pair(1, 2)), "Pair");
+    assert_eq!(variant_name!(&Enum::Unit), "Unit");
+    assert_eq!(variant_name!(WithDebug::Custom), "Custom");
+}
+
+#[test]
+#[should_panic(expected = "get!: expected enum constructor: Enum::Named, got Pair")]
+fn is_used_in_panic_messages_for_values_without_debug() {
+    get!(Enum::Pair(1, 2), Enum::Named, a);
+}
+
+#[test]
+#[should_panic(expected = "assert_same_variant!: expected the same variant, got Unit and Pair")]
+fn is_used_by_assert_same_variant() {
+    assert_same_variant!(Enum::Unit, Enum::Pair(1, 2));
+}
@@ -0,0 +1,51 @@
+use unsafe_get::VariantRefs;
+
+#[derive(Debug, PartialEq, VariantRefs)]
+#[variant_refs(derive(Debug, PartialEq))]
+enum Enum {
+    Named { a: i32, b: String },
+    Pair(i32, i32),
+    Unit,
+}
+
+#[derive(VariantRefs)]
+enum Generic<'a, T> {
+    Borrowed { value: &'a str },
+    Owned(T),
+}
+
+#[test]
+fn returns_shared_references() {
+    let value = Enum::Named {
+        a: 42,
+        b: "b".to_string(),
+    };
+    assert_eq!(
+        value.as_named_ref(),
+        Some(NamedRef {
+            a: &42,
+            b: &"b".to_string()
+        })
+    );
+    assert_eq!(value.as_pair_ref(), None);
+    assert_eq!(Enum::Pair(1, 2).as_pair_ref(), Some(PairRef(&1, &2)));
+    assert_eq!(Enum::Unit.as_pair_ref(), None);
+}
+
+#[test]
+fn returns_mutable_references() {
+    let mut value = Enum::Pair(1, 2);
+    let PairMut(a, b) = value.as_pair_mut().unwrap();
+    std::mem::swap(a, b);
+    assert_eq!(value, Enum::Pair(2, 1));
+    assert!(value.as_named_mut().is_none());
+}
+
+#[test]
+fn supports_generic_enums() {
+    let mut value: Generic<i32> = Generic::Owned(42);
+    *value.as_owned_mut().unwrap().0 += 1;
+    assert_eq!(value.as_owned_ref().unwrap().0, &43);
+    let value: Generic<i32> = Generic::Borrowed { value: "foo" };
+    assert_eq!(*value.as_borrowed_ref().unwrap().value, "foo");
+}
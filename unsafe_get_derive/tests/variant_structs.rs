@@ -0,0 +1,96 @@
+use std::convert::TryFrom;
+use unsafe_get::{UnexpectedVariant, VariantStructs};
+
+#[derive(Debug, PartialEq, VariantStructs)]
+#[variant_structs(derive(Debug, PartialEq, Clone))]
+enum Enum {
+    Named { a: i32, b: String },
+    Pair(i32, i32),
+    Unit,
+}
+
+#[derive(Debug, PartialEq, VariantStructs)]
+#[variant_structs(derive(Debug, PartialEq))]
+enum Generic<'a, T: Clone, U>
+where
+    U: Default,
+{
+    Borrowed { value: &'a T },
+    Owned { value: U },
+}
+
+#[test]
+fn converts_variants_into_structs() {
+    let value = Enum::Named {
+        a: 42,
+        b: "b".to_string(),
+    };
+    assert_eq!(
+        value.into_named_fields(),
+        Some(NamedFields {
+            a: 42,
+            b: "b".to_string()
+        })
+    );
+    assert_eq!(Enum::Pair(1, 2).into_named_fields(), None);
+    assert_eq!(Enum::Unit.into_named_fields(), None);
+}
+
+#[test]
+fn converts_structs_into_variants() {
+    let fields = NamedFields {
+        a: 42,
+        b: "b".to_string(),
+    };
+    assert_eq!(
+        Enum::from_named_fields(fields.clone()),
+        Enum::Named {
+            a: 42,
+            b: "b".to_string()
+        }
+    );
+    assert_eq!(fields.a, 42);
+}
+
+#[test]
+fn only_uses_the_generics_of_the_fields() {
+    let value = 42;
+    let borrowed: Generic<i32, u8> = Generic::Borrowed { value: &value };
+    assert_eq!(
+        borrowed.into_borrowed_fields(),
+        Some(BorrowedFields { value: &42 })
+    );
+    let owned: Generic<i32, u8> = Generic::from_owned_fields(OwnedFields { value: 3 });
+    assert_eq!(owned, Generic::Owned { value: 3 });
+}
+
+#[test]
+fn implements_from_and_try_from() {
+    let value: Enum = NamedFields {
+        a: 42,
+        b: "b".to_string(),
+    }
+    .into();
+    assert_eq!(
+        NamedFields::try_from(value),
+        Ok(NamedFields {
+            a: 42,
+            b: "b".to_string()
+        })
+    );
+    let error = NamedFields::try_from(Enum::Pair(1, 2)).unwrap_err();
+    assert_eq!(
+        (error.expected, error.actual.as_str()),
+        ("Enum::Named", "Pair")
+    );
+}
+
+#[test]
+fn try_from_composes_with_the_question_mark_operator() {
+    fn extract(value: Generic<i32, u8>) -> Result<u8, UnexpectedVariant> {
+        Ok(OwnedFields::try_from(value)?.value)
+    }
+    assert_eq!(extract(OwnedFields { value: 3 }.into()), Ok(3));
+    let value = 42;
+    assert!(extract(BorrowedFields { value: &value }.into()).is_err());
+}
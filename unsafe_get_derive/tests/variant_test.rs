@@ -0,0 +1,60 @@
+use std::env;
+use unsafe_get::variant_test;
+
+#[allow(dead_code)]
+#[derive(Debug)]
+enum Outcome {
+    Success { code: i32, output: String },
+    Failure(String),
+}
+
+fn run(code: i32) -> Outcome {
+    Outcome::Success {
+        code,
+        output: "done".to_string(),
+    }
+}
+
+#[variant_test(Outcome::Success)]
+fn checks_constructors() -> Outcome {
+    run(0)
+}
+
+#[variant_test(Outcome::Success { code: 0, .. })]
+fn checks_fields() -> Outcome {
+    run(0)
+}
+
+#[variant_test(Outcome::Success { code: 1, .. })]
+#[should_panic(
+    expected = "assert_variant_eq!: fields of Outcome::Success differ\n  field `code`:\n     left: 0\n    right: 1"
+)]
+fn panics_for_differing_fields() -> Outcome {
+    // The `color` feature colors the differing values.
+    env::set_var("NO_COLOR", "1");
+    run(0)
+}
+
+#[variant_test(Outcome::Failure)]
+#[should_panic(
+    expected = "assert_variant!: expected enum constructor: Outcome::Failure, got Success"
+)]
+fn panics_for_other_constructors() -> Outcome {
+    run(0)
+}
+
+#[variant_test(Outcome::Failure("boom"))]
+fn checks_tuple_fields() -> Outcome {
+    Outcome::Failure("boom".to_string())
+}
+
+#[variant_test(Outcome::Failure(..))]
+fn allows_ignoring_tuple_fields() -> Outcome {
+    Outcome::Failure("boom".to_string())
+}
+
+#[variant_test(Outcome::Failure("boom"))]
+#[should_panic(expected = "assert_variant_eq!: fields of Outcome::Failure differ\n  field `0`:")]
+fn panics_for_differing_tuple_fields() -> Outcome {
+    Outcome::Failure("bang".to_string())
+}